@@ -0,0 +1,393 @@
+use std::{collections::HashMap, fs};
+use serde::{Serialize, Deserialize, Deserializer};
+use uuid::Uuid;
+
+use crate::entity::{Entity, templates, equipment::{self, Slot}, state::ALIVE, attributes::Traits, dmg_type::DamageType,
+  dmg_resistance::DamageResistance, stats::Stats};
+use crate::scene::deck::Card;
+use crate::pack::Rarity;
+use crate::locale::{Locale, Localized};
+use crate::tr;
+pub mod trade;
+use trade::Trade;
+
+pub const DEFAULT_PATH: &str = "collections.json";
+
+/// A creature owned by a user, storing everything that was rolled by `EntityBuilder::build`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnedCreature {
+  pub id: Uuid,
+  pub template: String,
+  pub max_health: u8,
+  #[serde(deserialize_with = "trait_names")]
+  pub attributes: Vec<String>,
+  pub damage_resistance: Vec<(String, DamageResistance)>,
+  pub abilities: Vec<String>,
+  pub attacks: Vec<String>,
+  #[serde(default = "first_level")]
+  pub level: u8,
+  #[serde(default)]
+  pub experience: u32,
+  #[serde(default)]
+  pub equipment: Vec<String>,
+  #[serde(default)]
+  pub stats: Stats
+}
+
+fn first_level() -> u8 {
+  1
+}
+
+/// Trait names, creatures saved before traits had names stored them as a bitmask
+fn trait_names<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+  #[derive(Deserialize)]
+  #[serde(untagged)]
+  enum Stored {
+    Names(Vec<String>),
+    Bitmask(u8)
+  }
+
+  Ok(match Stored::deserialize(deserializer)? {
+    Stored::Names(names) => names,
+    Stored::Bitmask(bits) => Traits::from_bitmask(bits).names()
+  })
+}
+
+impl OwnedCreature {
+  pub fn from_entity(entity: &Entity) -> Self {
+    OwnedCreature {
+      id: entity.id,
+      template: entity.name.to_string(),
+      max_health: entity.base_max_health(),
+      attributes: entity.attributes.names(),
+      damage_resistance: entity.damage_resistance.iter().map(|(t, r)| (t.name.to_string(), *r)).collect(),
+      abilities: entity.abilities.iter().map(|ability| ability.name.to_string()).collect(),
+      attacks: entity.attacks.iter().map(|attack| attack.name.to_string()).collect(),
+      level: entity.level,
+      experience: entity.experience,
+      equipment: entity.equipment.iter().map(|item| item.name.to_string()).collect(),
+      stats: entity.stats
+    }
+  }
+
+  pub fn rarity(&self) -> Option<Rarity> {
+    let builder = templates::get(&self.template)?;
+    Some(Rarity::from_probability(builder.variant_probability(&Traits::from_names(&self.attributes))))
+  }
+
+  /// Rebuild the creature at full health, `None` if its template does not exist anymore
+  pub fn to_entity(&self) -> Option<Entity> {
+    let builder = templates::get(&self.template)?;
+
+    let abilities = builder.abilities.iter()
+      .filter(|ability| self.abilities.iter().any(|name| name == ability.name))
+      .copied().collect();
+    let attacks = builder.attacks.iter()
+      .filter(|attack| self.attacks.iter().any(|name| name == attack.name))
+      .copied().collect();
+    let damage_resistance = self.damage_resistance.iter()
+      .filter_map(|(name, r)| Some((DamageType::from_name(name)?, *r)))
+      .collect();
+
+    let mut entity = Entity { id: self.id, name: builder.name, description: builder.description,
+      max_health: self.max_health, current_health: self.max_health, abilities, battle_abilities: Vec::new(), attacks,
+      auras: builder.get_auras(0..=self.level),
+      state: ALIVE, stacks: Vec::new(), statuses: Vec::new(), modifiers: Vec::new(), shield: 0, evasion: builder.evasion,
+      stats: self.stats, damaged_this_turn: false, last_attacker: None, summoner: None,
+      ability_states: HashMap::new(), attributes: Traits::from_names(&self.attributes), damage_resistance,
+      level: self.level, experience: self.experience, growth: builder.growth, equipment: Vec::new() };
+    for item in self.equipment.iter().filter_map(|name| equipment::get(name)) {
+      entity.equip(item);
+    }
+
+    Some(entity)
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CardKind {
+  Attack,
+  Ability
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OwnedCard {
+  pub kind: CardKind,
+  pub name: String
+}
+
+impl OwnedCard {
+  pub fn from_card(card: &Card) -> Self {
+    match card {
+      Card::Attack(attack) => OwnedCard { kind: CardKind::Attack, name: attack.name.to_string() },
+      Card::Ability(ability) => OwnedCard { kind: CardKind::Ability, name: ability.name.to_string() }
+    }
+  }
+
+  pub fn to_card(&self) -> Option<Card> {
+    match self.kind {
+      CardKind::Attack => templates::find_attack(&self.name).map(Card::Attack),
+      CardKind::Ability => templates::find_ability(&self.name).map(Card::Ability)
+    }
+  }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Collection {
+  pub creatures: Vec<OwnedCreature>,
+  pub cards: Vec<OwnedCard>,
+  #[serde(default)]
+  pub items: Vec<String>,
+  #[serde(default)]
+  pub trades: Vec<Trade>
+}
+
+impl Collection {
+  pub fn describe(&self, locale: Locale) -> String {
+    let mut result = tr!(locale, "collection.creatures", self.creatures.len());
+    for (index, creature) in self.creatures.iter().enumerate() {
+      let rarity = creature.rarity().map_or(" ", |rarity| rarity.icon());
+      result += &tr!(locale, "collection.creature", index, rarity, creature.template,
+        tr!(locale, "entity.level", creature.level), creature.max_health);
+    }
+
+    result += &tr!(locale, "collection.items", self.items.len());
+    for item in self.items.iter().filter_map(|name| equipment::get(name)) {
+      result += &Localized(item, locale).to_string();
+    }
+
+    result += &tr!(locale, "collection.cards", self.cards.len());
+    for card in self.cards.iter() {
+      if let Some(card) = card.to_card() {
+        result += &Localized(&card, locale).to_string();
+      }
+    }
+
+    result
+  }
+
+  pub fn describe_creature(&self, index: usize, locale: Locale) -> Option<String> {
+    let creature = self.creatures.get(index)?;
+    let entity = creature.to_entity()?;
+    let rarity = creature.rarity()?;
+    Some(format!("[{}] {} `{}`\n{}", index, Localized(&rarity, locale), creature.id, entity.describe(locale)))
+  }
+
+  pub fn describe_trades(&self, user: u64, locale: Locale) -> String {
+    if self.trades.is_empty() {
+      return locale.get("trade.no_history").to_string();
+    }
+
+    let mut result = String::new();
+    for trade in self.trades.iter().rev() {
+      let (other, given, received) = if trade.proposer == user {
+        (trade.recipient, trade.offered.len(), trade.requested.len())
+      }
+      else {
+        (trade.proposer, trade.requested.len(), trade.offered.len())
+      };
+      result += &tr!(locale, "trade.history", Localized(&trade.status, locale), other, given, received, trade.created_at);
+    }
+    result
+  }
+
+  /// Evolve an owned creature, returns the narration if it was ready
+  pub fn evolve(&mut self, index: usize, item: Option<&str>, locale: Locale) -> Option<String> {
+    let creature = self.creatures.get_mut(index)?;
+    let entity = creature.to_entity()?;
+    let evolved = entity.evolved(item, &mut rand::thread_rng())?;
+    *creature = OwnedCreature::from_entity(&evolved);
+    Some(tr!(locale, "collection.evolved", entity.name, evolved.name))
+  }
+
+  /// Move an unequipped item onto a creature, whatever it replaces goes back to the items
+  pub fn equip(&mut self, index: usize, item_name: &str, locale: Locale) -> Result<String, String> {
+    let item_index = self.items.iter().position(|name| name.eq_ignore_ascii_case(item_name))
+      .ok_or(tr!(locale, "collection.no_spare_item", item_name))?;
+    let item = equipment::get(&self.items[item_index]).ok_or(tr!(locale, "collection.unknown_item", item_name))?;
+    let creature = self.creatures.get_mut(index).ok_or(tr!(locale, "collection.not_owned", index))?;
+    let mut entity = creature.to_entity().ok_or(tr!(locale, "collection.cannot_equip", index))?;
+
+    self.items.remove(item_index);
+    if let Some(replaced) = entity.equip(item) {
+      self.items.push(replaced.name.to_string());
+    }
+    *creature = OwnedCreature::from_entity(&entity);
+    Ok(tr!(locale, "collection.equipped", entity.name, item.name))
+  }
+
+  pub fn unequip(&mut self, index: usize, slot: Slot, locale: Locale) -> Result<String, String> {
+    let creature = self.creatures.get_mut(index).ok_or(tr!(locale, "collection.not_owned", index))?;
+    let mut entity = creature.to_entity().ok_or(tr!(locale, "collection.cannot_unequip", index))?;
+    let item = entity.unequip(slot)
+      .ok_or(tr!(locale, "collection.nothing_equipped", entity.name, slot.localized_name(locale)))?;
+
+    self.items.push(item.name.to_string());
+    *creature = OwnedCreature::from_entity(&entity);
+    Ok(tr!(locale, "collection.unequipped", entity.name, item.name))
+  }
+
+  pub fn owns(&self, creatures: &[Uuid]) -> bool {
+    creatures.iter().all(|id| self.creatures.iter().any(|creature| creature.id == *id))
+  }
+
+  fn take(&mut self, creatures: &[Uuid]) -> Vec<OwnedCreature> {
+    let (taken, kept) = self.creatures.drain(..).partition(|creature| creatures.contains(&creature.id));
+    self.creatures = kept;
+    taken
+  }
+}
+
+/// Every user's collection, keyed by Discord user ID and saved as JSON
+pub struct CollectionStore {
+  pub path: String,
+  pub collections: HashMap<u64, Collection>
+}
+
+impl CollectionStore {
+  pub fn load(path: &str) -> Self {
+    let collections = match fs::read_to_string(path) {
+      Ok(data) => serde_json::from_str(&data).expect("Could not parse collections."),
+      Err(_) => HashMap::new()
+    };
+
+    CollectionStore { path: path.to_string(), collections }
+  }
+
+  pub fn save(&self) -> std::io::Result<()> {
+    fs::write(&self.path, serde_json::to_string_pretty(&self.collections)?)
+  }
+
+  pub fn get(&self, user: u64) -> Option<&Collection> {
+    self.collections.get(&user)
+  }
+
+  pub fn get_mut(&mut self, user: u64) -> &mut Collection {
+    self.collections.entry(user).or_default()
+  }
+
+  pub fn add_creature(&mut self, user: u64, entity: &Entity) {
+    self.get_mut(user).creatures.push(OwnedCreature::from_entity(entity));
+  }
+
+  /// Copy the progress of an owned creature back into its owner's collection, returns whether it was owned
+  pub fn sync_entity(&mut self, entity: &Entity) -> bool {
+    for collection in self.collections.values_mut() {
+      if let Some(creature) = collection.creatures.iter_mut().find(|creature| creature.id == entity.id) {
+        *creature = OwnedCreature::from_entity(entity);
+        return true;
+      }
+    }
+
+    false
+  }
+
+  /// Swap the creatures of an accepted trade, nothing moves unless both users still own their side
+  pub fn execute_trade(&mut self, trade: &Trade, locale: Locale) -> Result<(), String> {
+    if !self.get(trade.proposer).is_some_and(|c| c.owns(&trade.offered)) {
+      return Err(tr!(locale, "trade.offered_gone", trade.proposer));
+    }
+    if !self.get(trade.recipient).is_some_and(|c| c.owns(&trade.requested)) {
+      return Err(tr!(locale, "trade.requested_gone", trade.recipient));
+    }
+
+    let offered = self.get_mut(trade.proposer).take(&trade.offered);
+    let requested = self.get_mut(trade.recipient).take(&trade.requested);
+    self.get_mut(trade.proposer).creatures.extend(requested);
+    self.get_mut(trade.recipient).creatures.extend(offered);
+    Ok(())
+  }
+
+  pub fn describe_trade(&self, trade: &Trade, locale: Locale) -> String {
+    trade.describe(self, locale)
+  }
+
+  /// Keep a closed trade in both users' history
+  pub fn log_trade(&mut self, trade: &Trade) {
+    self.get_mut(trade.proposer).trades.push(trade.clone());
+    self.get_mut(trade.recipient).trades.push(trade.clone());
+  }
+
+  pub fn add_card(&mut self, user: u64, card: &Card) {
+    self.get_mut(user).cards.push(OwnedCard::from_card(card));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const ALICE: u64 = 1;
+  const BOB: u64 = 2;
+
+  /// A store where each user owns a single slime, returns the store and both slimes' IDs
+  fn store() -> (CollectionStore, Uuid, Uuid) {
+    let mut store = CollectionStore { path: String::new(), collections: HashMap::new() };
+    let (alice, bob) = (templates::slime().build(), templates::slime().build());
+    store.add_creature(ALICE, &alice);
+    store.add_creature(BOB, &bob);
+    (store, alice.id, bob.id)
+  }
+
+  #[test]
+  fn trade_swaps_owned_creatures() {
+    let (mut store, alice, bob) = store();
+    let trade = Trade::new(ALICE, BOB, vec![alice], vec![bob]);
+
+    assert!(store.execute_trade(&trade, Locale::English).is_ok());
+    assert!(store.get(ALICE).unwrap().owns(&[bob]));
+    assert!(store.get(BOB).unwrap().owns(&[alice]));
+    assert!(!store.get(ALICE).unwrap().owns(&[alice]));
+  }
+
+  #[test]
+  fn trade_needs_the_offered_creatures() {
+    let (mut store, _, bob) = store();
+    let trade = Trade::new(ALICE, BOB, vec![bob], vec![bob]);
+
+    assert!(store.execute_trade(&trade, Locale::English).is_err());
+    assert!(store.get(BOB).unwrap().owns(&[bob]));
+    assert_eq!(store.get(ALICE).unwrap().creatures.len(), 1);
+  }
+
+  #[test]
+  fn trade_needs_the_requested_creatures() {
+    let (mut store, alice, _) = store();
+    let trade = Trade::new(ALICE, BOB, vec![alice], vec![Uuid::new_v4()]);
+
+    assert!(store.execute_trade(&trade, Locale::English).is_err());
+    assert!(store.get(ALICE).unwrap().owns(&[alice]));
+    assert_eq!(store.get(BOB).unwrap().creatures.len(), 1);
+  }
+
+  #[test]
+  fn trade_fails_with_unknown_users() {
+    let (mut store, alice, _) = store();
+    let trade = Trade::new(ALICE, 3, vec![alice], Vec::new());
+
+    assert!(store.execute_trade(&trade, Locale::English).is_err());
+    assert!(store.get(ALICE).unwrap().owns(&[alice]));
+  }
+
+  #[test]
+  fn trades_giving_nothing_are_empty() {
+    let (_, alice, _) = store();
+    assert!(Trade::new(ALICE, BOB, Vec::new(), Vec::new()).is_empty());
+    assert!(!Trade::new(ALICE, BOB, vec![alice], Vec::new()).is_empty());
+  }
+
+  #[test]
+  fn latest_trade_is_the_last_proposed() {
+    let (_, alice, bob) = store();
+    let mut book = trade::TradeBook::default();
+    let first = Trade::new(ALICE, BOB, vec![alice], vec![bob]);
+    let mut second = Trade::new(ALICE, BOB, vec![alice], Vec::new());
+    // Proposed within the same second, or with the clock going backwards
+    second.created_at = first.created_at - 1;
+    book.propose(&first);
+    book.propose(&second);
+
+    assert_eq!(book.latest_for(BOB).map(|trade| trade.id), Some(second.id));
+    assert!(book.latest_for(ALICE).is_none());
+  }
+}
@@ -0,0 +1,129 @@
+use std::{collections::HashMap, fmt::Display, time::{SystemTime, UNIX_EPOCH}};
+use serde::{Serialize, Deserialize};
+use uuid::Uuid;
+
+use super::CollectionStore;
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+/// Seconds before a pending trade expires
+pub const TRADE_EXPIRY: u64 = 300;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TradeStatus {
+  Pending,
+  Accepted,
+  Declined,
+  Countered,
+  Expired,
+  /// Accepted, but one of the users no longer owned their side
+  Failed
+}
+
+impl TradeStatus {
+  pub fn localized_name(&self, locale: Locale) -> &'static str {
+    match self {
+      TradeStatus::Pending => locale.get("trade.status.pending"),
+      TradeStatus::Accepted => locale.get("trade.status.accepted"),
+      TradeStatus::Declined => locale.get("trade.status.declined"),
+      TradeStatus::Countered => locale.get("trade.status.countered"),
+      TradeStatus::Expired => locale.get("trade.status.expired"),
+      TradeStatus::Failed => locale.get("trade.status.failed")
+    }
+  }
+}
+
+impl Display for Localized<'_, TradeStatus> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0.localized_name(self.1))
+  }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Trade {
+  pub id: Uuid,
+  pub proposer: u64,
+  pub recipient: u64,
+  pub offered: Vec<Uuid>,
+  pub requested: Vec<Uuid>,
+  pub created_at: u64,
+  /// Order in which the trade was proposed, set by `TradeBook::propose`
+  #[serde(default)]
+  pub sequence: u64,
+  pub status: TradeStatus
+}
+
+pub fn now() -> u64 {
+  SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+impl Trade {
+  pub fn new(proposer: u64, recipient: u64, offered: Vec<Uuid>, requested: Vec<Uuid>) -> Self {
+    Trade { id: Uuid::new_v4(), proposer, recipient, offered, requested, created_at: now(), sequence: 0,
+      status: TradeStatus::Pending }
+  }
+
+  /// Whether neither side gives anything
+  pub fn is_empty(&self) -> bool {
+    self.offered.is_empty() && self.requested.is_empty()
+  }
+
+  pub fn is_expired(&self) -> bool {
+    now() >= self.created_at + TRADE_EXPIRY
+  }
+
+  fn describe_side(store: &CollectionStore, user: u64, creatures: &[Uuid], locale: Locale) -> String {
+    if creatures.is_empty() {
+      return locale.get("trade.nothing").to_string();
+    }
+
+    let mut result = String::new();
+    for id in creatures.iter() {
+      match store.get(user).and_then(|c| c.creatures.iter().find(|creature| creature.id == *id)) {
+        Some(creature) => {
+          let rarity = creature.rarity().map_or(" ", |rarity| rarity.icon());
+          result += &format!("  {} **{}**  {} ❤️\n", rarity, creature.template, creature.max_health);
+        },
+        None => result += locale.get("trade.no_longer_owned")
+      }
+    }
+    result
+  }
+
+  pub fn describe(&self, store: &CollectionStore, locale: Locale) -> String {
+    tr!(locale, "trade.offer", self.proposer, Trade::describe_side(store, self.proposer, &self.offered, locale),
+      self.recipient, Trade::describe_side(store, self.recipient, &self.requested, locale),
+      Localized(&self.status, locale))
+  }
+}
+
+/// Trades waiting for an answer
+#[derive(Default)]
+pub struct TradeBook {
+  pub pending: HashMap<Uuid, Trade>,
+  /// Sequence number given to the next proposed trade
+  next_sequence: u64
+}
+
+impl TradeBook {
+  pub fn propose(&mut self, trade: &Trade) {
+    let mut trade = trade.clone();
+    trade.sequence = self.next_sequence;
+    self.next_sequence += 1;
+    self.pending.insert(trade.id, trade);
+  }
+
+  /// Latest pending trade addressed to `recipient`
+  pub fn latest_for(&self, recipient: u64) -> Option<&Trade> {
+    self.pending.values()
+      .filter(|trade| trade.recipient == recipient && !trade.is_expired())
+      .max_by_key(|trade| trade.sequence)
+  }
+
+  /// Remove a pending trade, closing it with `status`
+  pub fn close(&mut self, id: Uuid, status: TradeStatus) -> Option<Trade> {
+    let mut trade = self.pending.remove(&id)?;
+    trade.status = status;
+    Some(trade)
+  }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+use rand::{Rng, seq::SliceRandom};
+
+use crate::entity::{Entity, EntityBuilder, templates, challenge::ChallengeRating};
+use crate::locale::{Locale, Localized};
+
+/// Threat an encounter aims for when nobody else is in the scene to measure it against
+pub const SOLO_THREAT: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+  Easy,
+  Normal,
+  Hard,
+  Deadly
+}
+
+pub const DIFFICULTIES: [Difficulty; 4] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard, Difficulty::Deadly];
+
+impl Difficulty {
+  pub fn from_name(name: &str) -> Option<Self> {
+    DIFFICULTIES.into_iter().find(|difficulty| format!("{:?}", difficulty).eq_ignore_ascii_case(name))
+  }
+
+  /// Percentage of the other teams' threat an encounter of this difficulty aims for
+  pub fn threat_percent(&self) -> f32 {
+    match self {
+      Difficulty::Easy => 50.0,
+      Difficulty::Normal => 100.0,
+      Difficulty::Hard => 150.0,
+      Difficulty::Deadly => 200.0
+    }
+  }
+
+  pub fn localized_name(&self, locale: Locale) -> &'static str {
+    match self {
+      Difficulty::Easy => locale.get("difficulty.easy"),
+      Difficulty::Normal => locale.get("difficulty.normal"),
+      Difficulty::Hard => locale.get("difficulty.hard"),
+      Difficulty::Deadly => locale.get("difficulty.deadly")
+    }
+  }
+}
+
+impl Display for Localized<'_, Difficulty> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}", self.0.localized_name(self.1))
+  }
+}
+
+/// A creature that can show up in a biome, `weight` being its share of the rolls
+pub struct SpawnEntry {
+  pub template: &'static str,
+  pub weight: u32,
+  /// Easiest difficulty the creature shows up at
+  pub min_difficulty: Difficulty
+}
+
+/// Creatures living in a biome, rolled to fill up encounters
+pub struct SpawnTable {
+  pub name: &'static str,
+  pub description: &'static str,
+  pub entries: &'static [SpawnEntry]
+}
+
+pub static SPAWN_TABLES: [SpawnTable; 3] = [
+  SpawnTable { name: "Swamp", description: "Murky water full of things that want your blood.",
+    entries: &[
+      SpawnEntry { template: "Leech", weight: 6, min_difficulty: Difficulty::Easy },
+      SpawnEntry { template: "Slime", weight: 3, min_difficulty: Difficulty::Easy },
+      SpawnEntry { template: "Bloated Leech", weight: 2, min_difficulty: Difficulty::Hard }
+    ] },
+  SpawnTable { name: "Cave", description: "Damp, dark, and never as empty as it sounds.",
+    entries: &[
+      SpawnEntry { template: "Bat", weight: 6, min_difficulty: Difficulty::Easy },
+      SpawnEntry { template: "Slime", weight: 2, min_difficulty: Difficulty::Easy },
+      SpawnEntry { template: "Giant Slime", weight: 1, min_difficulty: Difficulty::Deadly }
+    ] },
+  SpawnTable { name: "Sewers", description: "Everything the city throws away, some of it still moving.",
+    entries: &[
+      SpawnEntry { template: "Slime", weight: 4, min_difficulty: Difficulty::Easy },
+      SpawnEntry { template: "Leech", weight: 3, min_difficulty: Difficulty::Easy },
+      SpawnEntry { template: "Bat", weight: 2, min_difficulty: Difficulty::Normal },
+      SpawnEntry { template: "Giant Slime", weight: 1, min_difficulty: Difficulty::Hard }
+    ] }
+];
+
+pub fn get(name: &str) -> Option<&'static SpawnTable> {
+  SPAWN_TABLES.iter().find(|table| table.name.eq_ignore_ascii_case(name))
+}
+
+/// Strength of an entity as its template's challenge rating, scaled by what it gained since it was built
+pub fn threat(entity: &Entity) -> f32 {
+  ChallengeRating::of(entity).map_or(0.0, |rating| rating.rating())
+}
+
+impl SpawnTable {
+  /// Roll creatures until their combined challenge rating reaches `target` or `max_size` of them are rolled.
+  /// Creatures that would overshoot the target by more than half are left out, unless nothing was rolled yet.
+  pub fn generate(&self, difficulty: Difficulty, target: f32, max_size: usize, rng: &mut impl Rng) -> Vec<Entity> {
+    let entries: Vec<(&SpawnEntry, EntityBuilder, f32)> = self.entries.iter()
+      .filter(|entry| entry.min_difficulty <= difficulty)
+      .filter_map(|entry| templates::get(entry.template).map(|builder| {
+        let rating = builder.challenge_rating().rating();
+        (entry, builder, rating)
+      }))
+      .collect();
+    let mut result = Vec::new();
+    let mut total = 0.0;
+
+    while total < target && result.len() < max_size {
+      let fitting: Vec<&(&SpawnEntry, EntityBuilder, f32)> = entries.iter()
+        .filter(|(_, _, rating)| result.is_empty() || total + rating <= target * 1.5)
+        .collect();
+      let (_, builder, rating) = match fitting.choose_weighted(rng, |(entry, _, _)| entry.weight) {
+        Ok(entry) => *entry,
+        Err(_) => break
+      };
+
+      total += rating;
+      result.push(builder.build_with(rng));
+    }
+
+    result
+  }
+}
+
+impl Display for SpawnTable {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let creatures: Vec<&str> = self.entries.iter().map(|entry| entry.template).collect();
+    writeln!(f, "**{}** {}\n  {}", self.name, creatures.join(", "), self.description)
+  }
+}
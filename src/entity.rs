@@ -1,18 +1,36 @@
-use std::{fmt::Display, collections::HashMap};
-use rand::random;
+use std::{fmt::Display, collections::HashMap, ops::RangeInclusive};
+use rand::Rng;
 use uuid::Uuid;
 
 pub mod state;
-use state::ALIVE;
+use state::{ALIVE, BURN_DAMAGE, Status};
 pub mod attributes;
+use attributes::{Trait, Traits};
 pub mod dmg_type;
-use dmg_type::{DamageType, PHYSICAL, POISON, ACID, VAMPIRIC};
+use dmg_type::{DamageType, PHYSICAL, POISON, ACID, VAMPIRIC, FIRE};
 pub mod dmg_resistance;
 use dmg_resistance::{DamageResistance, DamageResistance::NEUTRAL};
 pub mod ability;
-pub use ability::{Ability, AbilityTrigger, AbilityEffect};
+pub use ability::{Ability, AbilityTrigger, AbilityEffect, AbilityState, TriggerEvent};
 pub mod attack;
 pub use attack::Attack;
+pub mod templates;
+pub mod growth;
+use growth::{Growth, NO_GROWTH, MAX_LEVEL, experience_to_next};
+pub mod evolution;
+use evolution::Evolution;
+pub mod equipment;
+use equipment::{Equipment, Slot};
+pub mod consumable;
+pub mod aura;
+use aura::Aura;
+pub mod stats;
+pub mod summon;
+pub mod challenge;
+use stats::{Stats, Stat, Modifier, DamageSource, DamageRules};
+
+use crate::locale::{Locale, Localized, plural};
+use crate::tr;
 
 
 #[derive(Debug, Clone)]
@@ -23,41 +41,102 @@ pub struct Entity {
   pub max_health: u8,
   pub current_health: u8,
   pub abilities: Vec<&'static Ability>,
+  /// Abilities taught by cards, only known until the end of the battle and never saved to collections
+  pub battle_abilities: Vec<&'static Ability>,
   pub attacks: Vec<&'static Attack>,
+  pub auras: Vec<&'static Aura>,
   pub state: u8,
   pub stacks: Vec<(DamageType, u8)>,
-  pub attributes: u8,
-  pub damage_resistance: HashMap<DamageType, DamageResistance>
+  /// Status conditions with how many of the entity's turns they still last
+  pub statuses: Vec<(Status, u8)>,
+  /// Buffs, debuffs and resistance changes with how many of the entity's turns they still last
+  pub modifiers: Vec<(Modifier, u8)>,
+  pub shield: u8,
+  /// Chance out of 100 of dodging an attack that would otherwise hit
+  pub evasion: u8,
+  pub stats: Stats,
+  /// Whether the entity took damage since the start of the current turn
+  pub damaged_this_turn: bool,
+  /// ID of the last entity that damaged it in the scene, credited when it faints
+  pub last_attacker: Option<u8>,
+  /// ID of the entity that summoned it into the scene, if it did not join on its own
+  pub summoner: Option<u8>,
+  /// Cooldowns and charges of the abilities that already went off, by ability name
+  pub ability_states: HashMap<&'static str, AbilityState>,
+  pub attributes: Traits,
+  pub damage_resistance: HashMap<DamageType, DamageResistance>,
+  pub level: u8,
+  pub experience: u32,
+  pub growth: Growth,
+  pub equipment: Vec<&'static Equipment>
 }
 
 impl Display for Entity {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Entity> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(entity, locale) = *self;
     let mut ability_descs = String::new();
-    for ability in self.abilities.iter() {
-      ability_descs += &ability.to_string();
+    for ability in entity.abilities.iter().chain(entity.battle_abilities.iter()) {
+      ability_descs += &Localized(*ability, locale).to_string();
     }
     let mut attack_descs = String::new();
-    for attack in self.attacks.iter() {
-      attack_descs += &attack.to_string();
+    for attack in entity.attacks.iter() {
+      attack_descs += &Localized(*attack, locale).to_string();
+    }
+    let mut aura_descs = String::new();
+    for aura in entity.auras.iter() {
+      aura_descs += &Localized(*aura, locale).to_string();
+    }
+    let mut equipment_descs = String::new();
+    for item in entity.equipment.iter() {
+      equipment_descs += &Localized(*item, locale).to_string();
     }
-    write!(f, "**{}**  {}/{} ❤️  {}\n{}{}", self.name,
-      self.current_health, self.max_health, if self.is_alive() {' '} else {'💀'}, ability_descs, attack_descs)
+    write!(f, "**{}** {}  {}/{} ❤️{}  {}\n{}{}{}{}", entity.name, tr!(locale, "entity.level", entity.level),
+      entity.current_health, entity.max_health, entity.describe_status(), if entity.is_alive() {' '} else {'💀'},
+      ability_descs, aura_descs, attack_descs, equipment_descs)
   }
 }
 
+/// Roll a d100 against a chance out of 100, `None` when the chance is certain and nothing was rolled
+pub fn roll(rng: &mut impl Rng, chance: u8) -> Option<(bool, u8)> {
+  if chance >= 100 {
+    return None;
+  }
+
+  let roll = rng.gen_range(1..=100);
+  Some((roll <= chance, roll))
+}
+
+pub type Properties = (&'static [Trait], Vec<(DamageType, DamageResistance)>);
+pub type VariantProperties = (&'static [Trait], Vec<(DamageType, DamageResistance)>, f32);
+
 pub struct EntityBuilder {
   pub name: &'static str,
   pub description: &'static str,
   pub base_health: (u8, u8),
-  pub base_properties: (u8, Vec<(DamageType, DamageResistance)>),
-  pub variant_properties: Vec<(u8, Vec<(DamageType, DamageResistance)>, f32)>,
+  pub base_properties: Properties,
+  pub variant_properties: Vec<VariantProperties>,
   pub abilities: Vec<&'static Ability>,
-  pub attacks: Vec<&'static Attack>
+  pub attacks: Vec<&'static Attack>,
+  pub growth: Growth,
+  pub evolutions: Vec<Evolution>,
+  pub auras: Vec<&'static Aura>,
+  pub evasion: u8,
+  /// Ranges the attack and defense stats are rolled in
+  pub attack: (u8, u8),
+  pub defense: (u8, u8)
 }
 
 impl Entity {
   #[allow(dead_code)]
-  pub fn take_damage(&mut self, amt: u8, t: dmg_type::DamageType) -> (String, u8) {
+  /// Take damage of a type, `auras` being every aura currently affecting the entity
+  pub fn take_damage(&mut self, amt: u8, t: dmg_type::DamageType, source: DamageSource, auras: &[&Aura], locale: Locale)
+    -> (String, u8) {
     if self.state & ALIVE == 0 {
       return (String::new(), 0);
     }
@@ -65,34 +144,209 @@ impl Entity {
     let mut result = String::new();
 
     println!("\"{}\" is being attacked for {} {} damage.", self.name, amt, t);
-    result += &format!("**{}** is being attacked for {} {} damage.\n", self.name, amt, t);
+    result += &tr!(locale, "damage.attacked", self.name, amt, Localized(&t, locale));
+
+    let actual_amount = self.resisted_amount(amt, t, source, auras, &mut result, locale);
+    let actual_amount = self.apply_damage(actual_amount, t, auras, &mut result, locale);
 
-    let multiplier: f64;
-    match self.damage_resistance.get(&t).unwrap() {
+    (result, actual_amount)
+  }
+
+  /// Deal damage that already went through the damage formula, auras and shields can still soak it up.
+  /// Narrates into `result` and returns the damage actually taken.
+  fn apply_damage(&mut self, mut actual_amount: u8, t: DamageType, auras: &[&Aura], result: &mut String,
+    locale: Locale) -> u8 {
+    let reduction: u8 = auras.iter().map(|aura| aura.damage_reduction(t)).fold(0, u8::saturating_add);
+    if reduction > 0 && actual_amount > 0 {
+      let reduced = actual_amount.min(reduction);
+      actual_amount -= reduced;
+      println!("Auras reduced the damage \"{}\" took by {}.", self.name, reduced);
+      *result += &tr!(locale, "damage.aura_reduction", self.name, reduced);
+    }
+
+    // Shields soak up damage first unless the damage type goes through them
+    if self.shield > 0 && actual_amount > 0 && !t.ignore_shield() {
+      let absorbed = actual_amount.min(self.shield);
+      self.shield -= absorbed;
+      actual_amount -= absorbed;
+      println!("\"{}\"'s shield absorbed {} damage.", self.name, absorbed);
+      *result += &tr!(locale, "damage.absorbed", self.name, absorbed);
+    }
+
+    println!("\"{}\" took {} {} damage.", self.name, actual_amount, t);
+    *result += &tr!(locale, "damage.took", self.name, actual_amount, Localized(&t, locale));
+    self.current_health = self.current_health.saturating_sub(actual_amount);
+    if actual_amount > 0 {
+      self.damaged_this_turn = true;
+      if self.has_status(Status::Sleep) && self.current_health > 0 {
+        self.statuses.retain(|(status, _)| *status != Status::Sleep);
+        println!("\"{}\" woke up!", self.name);
+        *result += &tr!(locale, "status.woke_up", self.name);
+      }
+    }
+
+    actual_amount
+  }
+
+  /// Run an amount of damage through the damage formula, narrating the entity's resistance into `result`.
+  /// An aura setting the resistance wins over the entity's own.
+  fn resisted_amount(&self, amt: u8, t: DamageType, source: DamageSource, auras: &[&Aura], result: &mut String,
+    locale: Locale) -> u8 {
+    let resistance = auras.iter().rev().find_map(|aura| aura.resistance(t)).unwrap_or(self.resistance(t));
+    match resistance {
       DamageResistance::WEAKNESS => {
-        multiplier = 1.5;
         println!("\"{}\" is weak to {} damage!", self.name, t);
-        result += &format!("**{}** is weak to {} damage!\n", self.name, t);
+        *result += &tr!(locale, "damage.weakness", self.name, Localized(&t, locale));
       },
-      DamageResistance::NEUTRAL => multiplier = 1.0,
+      DamageResistance::NEUTRAL => {},
       DamageResistance::RESISTANCE => {
-        multiplier = 0.5;
         println!("\"{}\" is resistant to {} damage!", self.name, t);
-        result += &format!("**{}** is resistant to {} damage!\n", self.name, t);
+        *result += &tr!(locale, "damage.resistance", self.name, Localized(&t, locale));
       },
       DamageResistance::IMMUNITY => {
-        multiplier = 0.0;
         println!("\"{}\" is immune to {} damage!", self.name, t);
-        result += &format!("**{}** is immune to {} damage!\n", self.name, t);
+        *result += &tr!(locale, "damage.immunity", self.name, Localized(&t, locale));
       }
+    };
+
+    stats::damage(amt, source, self.stat(Stat::Defense), resistance)
+  }
+
+  /// Stack up damage of a stacking type, dealt at the start of the entity's turns
+  pub fn add_stacks(&mut self, amt: u8, t: DamageType, source: DamageSource, auras: &[&Aura], locale: Locale) -> String {
+    if self.state & ALIVE == 0 {
+      return String::new();
     }
 
-    let actual_amount = (amt as f64 * multiplier) as u8;
-    println!("\"{}\" took {} {} damage.", self.name, actual_amount, t);
-    result += &format!("**{}** took {} {} damage.\n", self.name, actual_amount, t);
-    self.current_health -= actual_amount;
+    let mut result = String::new();
+    let actual_amount = self.resisted_amount(amt, t, source, auras, &mut result, locale);
+    if actual_amount == 0 {
+      return result;
+    }
 
-    (result, actual_amount)
+    match self.stacks.iter_mut().find(|(dt, _)| *dt == t) {
+      Some((_, stacks)) => *stacks = stacks.saturating_add(actual_amount),
+      None => self.stacks.push((t, actual_amount))
+    }
+    println!("\"{}\" got {} {} stacks.", self.name, actual_amount, t);
+    result += &tr!(locale, "damage.stacks", self.name, actual_amount, Localized(&t, locale));
+
+    result
+  }
+
+  /// Deal one damage per stack, then remove a stack of each type.
+  /// Stacks were resisted when they were added, so they go straight to the entity's health.
+  pub fn tick_stacks(&mut self, auras: &[&Aura], locale: Locale) -> String {
+    let mut result = String::new();
+
+    for (t, amt) in self.stacks.clone() {
+      if self.is_alive() {
+        self.apply_damage(amt, t, auras, &mut result, locale);
+      }
+    }
+    for (_, stacks) in self.stacks.iter_mut() {
+      *stacks -= 1;
+    }
+    self.stacks.retain(|(_, stacks)| *stacks > 0);
+
+    result
+  }
+
+  /// Inflict a status for a number of turns, a status already there lasts for the longest of both
+  pub fn add_status(&mut self, status: Status, turns: u8, locale: Locale) -> String {
+    if !self.is_alive() || self.current_health == 0 || turns == 0 {
+      return String::new();
+    }
+
+    match self.statuses.iter_mut().find(|(s, _)| *s == status) {
+      Some((_, left)) => *left = (*left).max(turns),
+      None => self.statuses.push((status, turns))
+    }
+    println!("\"{}\" is now affected by {} for {} turns.", self.name, status, turns);
+    tr!(locale, plural(turns, "status.inflicted.one", "status.inflicted.other"), self.name,
+      Localized(&status, locale), turns)
+  }
+
+  pub fn has_status(&self, status: Status) -> bool {
+    self.statuses.iter().any(|(s, _)| *s == status)
+  }
+
+  /// The status keeping the entity from acting, if any
+  pub fn incapacitated_by(&self) -> Option<Status> {
+    self.statuses.iter().map(|(status, _)| *status).find(|status| status.prevents_action())
+  }
+
+  /// Burning entities take fire damage, meant for the start of the entity's turns
+  pub fn burn(&mut self, auras: &[&Aura], rules: DamageRules, locale: Locale) -> String {
+    if !self.has_status(Status::Burn) {
+      return String::new();
+    }
+
+    self.take_damage(BURN_DAMAGE, FIRE, DamageSource { attack: 0, rules }, auras, locale).0
+  }
+
+  /// Count down every status by a turn, meant for the end of the entity's turns
+  pub fn tick_statuses(&mut self, locale: Locale) -> String {
+    let mut result = String::new();
+
+    for (status, left) in self.statuses.iter_mut() {
+      *left -= 1;
+      if *left == 0 && self.state & ALIVE != 0 {
+        println!("\"{}\" is no longer affected by {}.", self.name, status);
+        result += &tr!(locale, "status.expired", self.name, Localized(status, locale));
+      }
+    }
+    self.statuses.retain(|(_, left)| *left > 0);
+
+    for (_, left) in self.modifiers.iter_mut() {
+      *left -= 1;
+    }
+    self.modifiers.retain(|(_, left)| *left > 0);
+
+    result
+  }
+
+  /// Apply a buff, a debuff or a resistance change for a number of turns
+  pub fn add_modifier(&mut self, modifier: Modifier, turns: u8, locale: Locale) -> String {
+    if !self.is_alive() || turns == 0 {
+      return String::new();
+    }
+
+    self.modifiers.push((modifier, turns));
+    println!("\"{}\" got {:?} for {} turns.", self.name, modifier, turns);
+    tr!(locale, plural(turns, "modifier.added.one", "modifier.added.other"), self.name, modifier.icon(), turns)
+  }
+
+  /// A stat with every modifier on the entity applied, evasion staying a chance out of 100
+  pub fn stat(&self, stat: Stat) -> u8 {
+    let base = match stat {
+      Stat::Attack => self.stats.attack,
+      Stat::Defense => self.stats.defense,
+      Stat::Evasion => self.evasion
+    };
+    let bonus: i16 = self.modifiers.iter().map(|(modifier, _)| match modifier {
+      Modifier::Stat(s, amount) if *s == stat => *amount as i16,
+      _ => 0
+    }).sum();
+    let max = if stat == Stat::Evasion { 100 } else { u8::MAX as i16 };
+    (base as i16 + bonus).clamp(0, max) as u8
+  }
+
+  /// Attack and defense with every modifier on the entity applied
+  pub fn current_stats(&self) -> Stats {
+    Stats { attack: self.stat(Stat::Attack), defense: self.stat(Stat::Defense) }
+  }
+
+  /// Remove every stack of a damage type, returns how many there were
+  pub fn clear_stacks(&mut self, t: DamageType) -> u8 {
+    let cleared = self.stacks.iter().filter(|(dt, _)| *dt == t).map(|(_, stacks)| *stacks).sum();
+    self.stacks.retain(|(dt, _)| *dt != t);
+    cleared
+  }
+
+  pub fn add_shield(&mut self, amt: u8) -> u8 {
+    self.shield = self.shield.saturating_add(amt);
+    self.shield
   }
 
   pub fn heal(&mut self, amt: u8) -> u8 {
@@ -103,7 +357,7 @@ impl Entity {
 
     let old_health = self.current_health;
 
-    self.current_health += amt;
+    self.current_health = self.current_health.saturating_add(amt);
     if self.current_health >= self.max_health {
       self.current_health = self.max_health;
     }
@@ -111,10 +365,62 @@ impl Entity {
     self.current_health - old_health
   }
 
+  /// Resistance to a damage type, upgraded by whatever the entity has equipped
+  pub fn resistance(&self, t: DamageType) -> DamageResistance {
+    // The latest resistance change wins over everything else
+    let changed = self.modifiers.iter().rev().find_map(|(modifier, _)| match modifier {
+      Modifier::Resistance(dt, r) if *dt == t => Some(*r),
+      _ => None
+    });
+    if let Some(r) = changed {
+      return r;
+    }
+
+    let base = self.damage_resistance.get(&t).copied().unwrap_or(NEUTRAL);
+    self.equipment.iter().filter_map(|item| item.resistance(t)).fold(base, |best, r| best.max(r))
+  }
+
+  /// Natural attacks followed by the ones granted by equipment
+  pub fn known_attacks(&self) -> impl Iterator<Item = &'static Attack> + '_ {
+    self.attacks.iter().copied()
+      .chain(self.equipment.iter().flat_map(|item| item.attacks.iter().copied()))
+  }
+
+  /// Natural abilities followed by the ones taught by cards and the ones granted by equipment
+  pub fn known_abilities(&self) -> impl Iterator<Item = &'static Ability> + '_ {
+    self.abilities.iter().copied()
+      .chain(self.battle_abilities.iter().copied())
+      .chain(self.equipment.iter().flat_map(|item| item.abilities.iter().copied()))
+  }
+
+  /// Equip an item, returning the one it replaced in the same slot
+  pub fn equip(&mut self, item: &'static Equipment) -> Option<&'static Equipment> {
+    let replaced = self.unequip(item.slot);
+    self.max_health = self.max_health.saturating_add(item.max_health);
+    if self.is_alive() {
+      self.current_health = self.current_health.saturating_add(item.max_health);
+    }
+    self.equipment.push(item);
+    replaced
+  }
+
+  pub fn unequip(&mut self, slot: Slot) -> Option<&'static Equipment> {
+    let index = self.equipment.iter().position(|item| item.slot == slot)?;
+    let item = self.equipment.remove(index);
+    self.max_health = self.max_health.saturating_sub(item.max_health).max(1);
+    self.current_health = self.current_health.min(self.max_health);
+    Some(item)
+  }
+
+  /// Max health without any equipment bonus
+  pub fn base_max_health(&self) -> u8 {
+    self.equipment.iter().fold(self.max_health, |health, item| health.saturating_sub(item.max_health))
+  }
+
   pub fn get_attack(&self, attack_name: &str) -> Option<Attack> {
-    for attack in self.attacks.iter() {
+    for attack in self.known_attacks() {
       if attack.name == attack_name {
-        return Some((*attack).clone());
+        return Some(*attack);
       }
     }
 
@@ -122,19 +428,62 @@ impl Entity {
   }
 
   #[allow(dead_code)]
-  pub fn check_for_trigger(&mut self, trigger: AbilityTrigger, ability_queue: &mut Vec<(Ability, u8, u8)>, source: u8, target: u8) -> String {
+  pub fn check_for_trigger(&mut self, trigger: AbilityTrigger, ability_queue: &mut Vec<(Ability, u8, u8)>, event: &TriggerEvent, rng: &mut impl Rng, locale: Locale) -> String {
     let mut result = String::new();
-    for ability in self.abilities.iter() {
-      if ability.trigger.match_(&trigger) {
-        println!("\"{}\" has been triggered! (#{})", ability.name, source);
-        result += &format!("`{}` has been triggered! (#{})\n", ability.name, source);
+    for ability in self.known_abilities().collect::<Vec<_>>() {
+      if ability.trigger.match_(&trigger) &&
+        self.ability_state(ability).is_ready(ability) &&
+        ability.trigger.conditions.iter().all(|condition| condition.is_met(self, event)) {
+        if let Some((success, rolled)) = roll(rng, ability.proc_chance) {
+          if !success {
+            println!("\"{}\" did not go off ({} > {}%)", ability.name, rolled, ability.proc_chance);
+            result += &tr!(locale, "ability.no_proc", ability.name, rolled, ability.proc_chance);
+            continue;
+          }
+          result += &tr!(locale, "ability.proc", ability.name, rolled, ability.proc_chance);
+        }
 
-        ability_queue.push(((*ability).clone(), source, target));
+        let state = self.ability_states.entry(ability.name).or_default();
+        state.cooldown = ability.cooldown;
+        state.charges_used = state.charges_used.saturating_add(1);
+
+        println!("\"{}\" has been triggered! (#{})", ability.name, event.source);
+        result += &tr!(locale, "ability.triggered", ability.name, event.source);
+
+        ability_queue.push((*ability, event.source, event.target));
       }
     }
     result
   }
 
+  pub fn ability_state(&self, ability: &Ability) -> AbilityState {
+    self.ability_states.get(ability.name).copied().unwrap_or_default()
+  }
+
+  /// Count down every ability cooldown by a turn
+  pub fn tick_cooldowns(&mut self) {
+    for state in self.ability_states.values_mut() {
+      state.cooldown = state.cooldown.saturating_sub(1);
+    }
+  }
+
+  /// Remaining cooldown and charges of an ability, empty when it has neither
+  fn describe_ability_state(&self, ability: &Ability, locale: Locale) -> String {
+    let state = self.ability_state(ability);
+    let mut limits = Vec::new();
+    if state.cooldown > 0 {
+      limits.push(tr!(locale, plural(state.cooldown, "ability.cooldown.one", "ability.cooldown.other"), state.cooldown));
+    }
+    if let Some(charges) = ability.charges {
+      limits.push(tr!(locale, "ability.charges", charges.saturating_sub(state.charges_used), charges));
+    }
+
+    if limits.is_empty() {
+      return String::new();
+    }
+    format!("    {}\n", limits.join(", "))
+  }
+
   pub fn died(&mut self) -> bool {
     if self.current_health > 0 || self.state & ALIVE == 0 {
       return false;
@@ -145,39 +494,222 @@ impl Entity {
     true
   }
 
+  /// Bring a fainted entity back with `percent` of its max health, at least 1, returns the health it got back
+  pub fn revive(&mut self, percent: u8) -> u8 {
+    if self.is_alive() {
+      return 0;
+    }
+
+    let health = ((self.max_health as u32 * percent as u32) / 100).clamp(1, self.max_health as u32) as u8;
+    self.current_health = health;
+    self.state |= ALIVE;
+    self.stacks.clear();
+    self.statuses.clear();
+    self.modifiers.clear();
+    self.last_attacker = None;
+    health
+  }
+
   pub fn is_alive(&self) -> bool {
     self.state & ALIVE != 0
   }
 
-  pub fn describe(&self) -> String {
+  /// Shield and stacks, shown next to the entity's health
+  fn describe_status(&self) -> String {
+    let mut result = String::new();
+    if self.shield > 0 {
+      result += &format!(" {} 🛡️", self.shield);
+    }
+    if self.stat(Stat::Evasion) > 0 {
+      result += &format!(" {}% 💨", self.stat(Stat::Evasion));
+    }
+    if self.current_stats() != Stats::default() {
+      result += &format!(" {} ⚔️ {} 🧱", self.stat(Stat::Attack), self.stat(Stat::Defense));
+    }
+    for (modifier, left) in self.modifiers.iter() {
+      result += &format!(" ({}){}", modifier.icon(), left);
+    }
+    for (t, stacks) in self.stacks.iter() {
+      result += &format!(" {}x{}", stacks, t.icon);
+    }
+    for (status, left) in self.statuses.iter() {
+      result += &format!(" {}{}", status.icon(), left);
+    }
+    result
+  }
+
+  pub fn describe(&self, locale: Locale) -> String {
+    let mut trait_descs = String::new();
+    for t in self.attributes.iter() {
+      trait_descs += &Localized(t, locale).to_string();
+    }
     let mut ability_descs = String::new();
-    for ability in self.abilities.iter() {
-      ability_descs += &ability.to_string();
+    for ability in self.abilities.iter().chain(self.battle_abilities.iter()) {
+      ability_descs += &Localized(*ability, locale).to_string();
+      ability_descs += &self.describe_ability_state(ability, locale);
     }
     let mut attack_descs = String::new();
     for attack in self.attacks.iter() {
-      attack_descs += &attack.to_string();
+      attack_descs += &Localized(*attack, locale).to_string();
+    }
+    let mut aura_descs = String::new();
+    for aura in self.auras.iter() {
+      aura_descs += &Localized(*aura, locale).to_string();
+    }
+    let mut equipment_descs = String::new();
+    for item in self.equipment.iter() {
+      equipment_descs += &Localized(*item, locale).to_string();
+      for ability in item.abilities.iter() {
+        equipment_descs += &self.describe_ability_state(ability, locale);
+      }
+    }
+    let mut evolution_descs = String::new();
+    if let Some(builder) = templates::get(self.name) {
+      for evolution in builder.evolutions.iter() {
+        evolution_descs += &Localized(evolution, locale).to_string();
+      }
     }
-    format!("**{}** {}/{} ❤️  {}\n{}\n{}{}", self.name, self.current_health, self.max_health,
-            if self.is_alive() {' '} else {'💀'}, self.description, ability_descs, attack_descs)
+    format!("**{}** {} ({}/{} xp) {}/{} ❤️{}  {}\n{}\n{}{}{}{}{}{}", self.name, tr!(locale, "entity.level", self.level),
+            self.experience, experience_to_next(self.level), self.current_health, self.max_health, self.describe_status(),
+            if self.is_alive() {' '} else {'💀'}, self.description, trait_descs, ability_descs, aura_descs, attack_descs,
+            equipment_descs, evolution_descs)
+  }
+
+  /// The entity this one evolves into if any of its template's evolutions is ready
+  pub fn evolved(&self, item: Option<&str>, rng: &mut impl Rng) -> Option<Entity> {
+    let builder = templates::get(self.name)?;
+    let evolution = builder.evolutions.iter().find(|evolution| evolution.is_met(self, item))?;
+    Some(templates::get(evolution.into)?.evolve(self, rng))
+  }
+
+  /// Attack damage once scaled by the entity's level and boosted by its equipment
+  pub fn attack_damage(&self, attack: &Attack) -> u8 {
+    let scaled = (attack.damage as f32 * self.growth.damage_multiplier(self.level)).min(u8::MAX as f32) as u8;
+    self.equipment.iter().fold(scaled, |damage, item| damage.saturating_add(item.damage_bonus(attack.t)))
+  }
+
+  /// Gain experience, levelling up as many times as it allows
+  pub fn gain_experience(&mut self, amt: u32, rng: &mut impl Rng, locale: Locale) -> String {
+    if self.level >= MAX_LEVEL {
+      return String::new();
+    }
+
+    let mut result = String::new();
+    println!("\"{}\" gained {} experience.", self.name, amt);
+    result += &tr!(locale, "experience.gained", self.name, amt);
+
+    self.experience += amt;
+    while self.level < MAX_LEVEL && self.experience >= experience_to_next(self.level) {
+      self.experience -= experience_to_next(self.level);
+      result += &self.level_up(rng, locale);
+    }
+    if self.level >= MAX_LEVEL {
+      self.experience = 0;
+    }
+
+    result
+  }
+
+  fn level_up(&mut self, rng: &mut impl Rng, locale: Locale) -> String {
+    let mut result = String::new();
+
+    self.level += 1;
+    self.max_health = self.max_health.saturating_add(self.growth.health_per_level);
+    if self.is_alive() {
+      self.current_health = self.current_health.saturating_add(self.growth.health_per_level).min(self.max_health);
+    }
+    println!("\"{}\" reached level {}!", self.name, self.level);
+    result += &tr!(locale, "experience.level_up", self.name, self.level);
+
+    // Learn whatever the template unlocks at this level
+    if let Some(builder) = templates::get(self.name) {
+      for attack in builder.get_attacks(&self.attributes, self.level..=self.level, rng) {
+        if !self.attacks.iter().any(|a| a.name == attack.name) {
+          self.attacks.push(attack);
+          println!("\"{}\" learned the \"{}\" attack!", self.name, attack.name);
+          result += &tr!(locale, "experience.learned_attack", self.name, attack.name);
+        }
+      }
+      for ability in builder.get_abilities(&self.attributes, self.level..=self.level, rng) {
+        if !self.abilities.iter().any(|a| a.name == ability.name) {
+          self.abilities.push(ability);
+          println!("\"{}\" learned the \"{}\" ability!", self.name, ability.name);
+          result += &tr!(locale, "experience.learned_ability", self.name, ability.name);
+        }
+      }
+      for aura in builder.get_auras(self.level..=self.level) {
+        if !self.auras.iter().any(|a| a.name == aura.name) {
+          self.auras.push(aura);
+          println!("\"{}\" learned the \"{}\" aura!", self.name, aura.name);
+          result += &tr!(locale, "experience.learned_aura", self.name, aura.name);
+        }
+      }
+    }
+
+    result
   }
 }
 
 impl EntityBuilder {
   pub fn new(name: &'static str, description: &'static str,
-    base_health: (u8, u8), base_properties: (u8, Vec<(DamageType, DamageResistance)>),
-    variant_properties: Vec<(u8, Vec<(DamageType, DamageResistance)>, f32)>,
+    base_health: (u8, u8), base_properties: Properties,
+    variant_properties: Vec<VariantProperties>,
     abilities: Vec<&'static Ability>, attacks: Vec<&'static Attack>) -> Self {
       EntityBuilder { name, description, base_health,
-        base_properties, variant_properties, abilities, attacks }
+        base_properties, variant_properties, abilities, attacks, growth: NO_GROWTH, evolutions: Vec::new(),
+        auras: Vec::new(), evasion: 0, attack: (0, 0), defense: (0, 0) }
+  }
+
+  pub fn with_evolution(mut self, evolution: Evolution) -> Self {
+    self.evolutions.push(evolution);
+    self
+  }
+
+  pub fn with_aura(mut self, aura: &'static Aura) -> Self {
+    self.auras.push(aura);
+    self
+  }
+
+  pub fn with_evasion(mut self, evasion: u8) -> Self {
+    self.evasion = evasion;
+    self
   }
 
-  fn get_base_health(&self) -> u8 {
-    (random::<f32>() * (self.base_health.1 - self.base_health.0 + 1) as f32) as u8 + self.base_health.0
+  pub fn with_stats(mut self, attack: (u8, u8), defense: (u8, u8)) -> Self {
+    self.attack = attack;
+    self.defense = defense;
+    self
   }
 
-  fn get_properties(&self) -> (u8, HashMap<DamageType, DamageResistance>) {
-    let mut traits = self.base_properties.0;
+  pub fn with_growth(mut self, growth: Growth) -> Self {
+    self.growth = growth;
+    self
+  }
+
+  fn get_base_health(&self, rng: &mut impl Rng) -> u8 {
+    (rng.gen::<f32>() * (self.base_health.1 - self.base_health.0 + 1) as f32) as u8 + self.base_health.0
+  }
+
+  fn get_stats(&self, rng: &mut impl Rng) -> Stats {
+    let mut roll = |(min, max): (u8, u8)| (rng.gen::<f32>() * (max - min + 1) as f32) as u8 + min;
+    Stats { attack: roll(self.attack), defense: roll(self.defense) }
+  }
+
+  fn get_properties(&self, rng: &mut impl Rng) -> (Traits, HashMap<DamageType, DamageResistance>) {
+    let mut traits = Traits::from(self.base_properties.0);
+
+    for (t, _, prob) in self.variant_properties.iter() {
+      if rng.gen::<f32>() <= *prob {
+        traits.extend(t);
+      }
+    }
+
+    let resistances = self.get_resistances(&traits);
+    (traits, resistances)
+  }
+
+  /// Resistances of the base properties and of every variant found in `traits`
+  fn get_resistances(&self, traits: &Traits) -> HashMap<DamageType, DamageResistance> {
     let mut resistances: HashMap<DamageType, DamageResistance> = HashMap::from([
       (PHYSICAL, NEUTRAL),
       (POISON, NEUTRAL),
@@ -189,27 +721,88 @@ impl EntityBuilder {
       resistances.insert(*k, *v);
     }
 
-    for (t, r, prob) in self.variant_properties.iter() {
-      if random::<f32>() <= *prob {
-        traits |= *t;
+    for (t, r, _) in self.variant_properties.iter() {
+      if traits.contains_all(t) {
         for (k, v) in r.iter() {
           resistances.insert(*k, *v);
         }
       }
     }
 
-    (traits, resistances)
+    resistances
   }
 
-  fn get_attacks(&self, traits: u8) -> Vec<&'static Attack> {
+  /// Every trait this template can have, either from its base or from a variant
+  pub fn possible_traits(&self) -> Traits {
+    let mut traits = Traits::from(self.base_properties.0);
+    for (t, _, _) in self.variant_properties.iter() {
+      traits.extend(t);
+    }
+    traits
+  }
+
+  /// Turn an existing entity into one of this template, keeping its identity, progress, health ratio,
+  /// what it already learned and any trait this template can also have
+  pub fn evolve(&self, entity: &Entity, rng: &mut impl Rng) -> Entity {
+    let mut result = self.build_with(rng);
+    let mut traits = Traits::from(self.base_properties.0);
+    traits.extend(entity.attributes.intersection(&self.possible_traits()).as_slice());
+
+    result.id = entity.id;
+    result.level = entity.level;
+    result.experience = entity.experience;
+    result.damage_resistance = self.get_resistances(&traits);
+    result.abilities = entity.abilities.clone();
+    for ability in self.get_abilities(&traits, 0..=entity.level, rng) {
+      if !result.abilities.iter().any(|a| a.name == ability.name) {
+        result.abilities.push(ability);
+      }
+    }
+    result.battle_abilities = entity.battle_abilities.clone();
+    result.attacks = entity.attacks.clone();
+    for attack in self.get_attacks(&traits, 0..=entity.level, rng) {
+      if !result.attacks.iter().any(|a| a.name == attack.name) {
+        result.attacks.push(attack);
+      }
+    }
+    result.auras = self.get_auras(0..=entity.level);
+    result.attributes = traits;
+    // Health grows by how much sturdier the new template is, so a well rolled creature stays one
+    let previous = templates::get(entity.name).map_or((self.base_health.0, self.growth),
+      |builder| (builder.base_health.0, builder.growth));
+    let growth = self.growth.health_per_level.saturating_sub(previous.1.health_per_level);
+    result.max_health = entity.base_max_health()
+      .saturating_add(self.base_health.0.saturating_sub(previous.0))
+      .saturating_add(growth.saturating_mul(entity.level.saturating_sub(1)));
+    result.stacks = entity.stacks.clone();
+    result.statuses = entity.statuses.clone();
+    result.modifiers = entity.modifiers.clone();
+    result.shield = entity.shield;
+    result.stats = entity.stats;
+    result.ability_states = entity.ability_states.clone();
+    result.last_attacker = entity.last_attacker;
+    result.summoner = entity.summoner;
+    for item in entity.equipment.iter() {
+      result.equip(item);
+    }
+    result.state = entity.state;
+    result.current_health = if entity.is_alive() {
+      ((result.max_health as f32 * entity.current_health as f32 / entity.max_health as f32).round() as u8).max(1)
+    }
+    else { 0 };
+
+    result
+  }
+
+  /// Roll the attacks available to `traits` that unlock within `levels`
+  pub fn get_attacks(&self, traits: &Traits, levels: RangeInclusive<u8>, rng: &mut impl Rng) -> Vec<&'static Attack> {
     let mut result = vec![];
 
     for attack in self.attacks.iter() {
-      let r = attack.required_traits;
-      let f = attack.forbidden_traits;
-      if r & traits == r &&
-        f & traits == 0 &&
-        random::<f32>() <= attack.prob {
+      if traits.contains_all(attack.required_traits) &&
+        !traits.contains_any(attack.forbidden_traits) &&
+        levels.contains(&attack.min_level) &&
+        rng.gen::<f32>() <= attack.prob {
           result.push(*attack);
         }
     }
@@ -217,15 +810,20 @@ impl EntityBuilder {
     result
   }
 
-  fn get_abilities(&self, traits: u8) -> Vec<&'static Ability> {
+  /// Auras unlocking within `levels`, every creature of the template gets them
+  pub fn get_auras(&self, levels: RangeInclusive<u8>) -> Vec<&'static Aura> {
+    self.auras.iter().filter(|aura| levels.contains(&aura.min_level)).copied().collect()
+  }
+
+  /// Roll the abilities available to `traits` that unlock within `levels`
+  pub fn get_abilities(&self, traits: &Traits, levels: RangeInclusive<u8>, rng: &mut impl Rng) -> Vec<&'static Ability> {
     let mut result = vec![];
 
     for ability in self.abilities.iter() {
-      let r = ability.required_traits;
-      let f = ability.forbidden_traits;
-      if r & traits == r &&
-        f & traits == 0 &&
-        random::<f32>() <= ability.probability {
+      if traits.contains_all(ability.required_traits) &&
+        !traits.contains_any(ability.forbidden_traits) &&
+        levels.contains(&ability.min_level) &&
+        rng.gen::<f32>() <= ability.probability {
           result.push(*ability);
         }
     }
@@ -233,12 +831,144 @@ impl EntityBuilder {
     result
   }
 
+  /// Chance of rolling every variant found in `traits`, variants without a trait of their own are ignored
+  pub fn variant_probability(&self, traits: &Traits) -> f32 {
+    let mut result = 1.0;
+    let base = Traits::from(self.base_properties.0);
+
+    for (t, _, prob) in self.variant_properties.iter() {
+      if !base.contains_all(t) && traits.contains_all(t) {
+        result *= *prob;
+      }
+    }
+
+    result
+  }
+
   #[allow(dead_code)]
   pub fn build(&self) -> Entity {
-    let max_health = self.get_base_health();
-    let properties = self.get_properties();
+    self.build_with(&mut rand::thread_rng())
+  }
+
+  /// Build an entity with every roll drawn from `rng`, so that seeded scenes spawn the same creatures
+  pub fn build_with(&self, rng: &mut impl Rng) -> Entity {
+    let max_health = self.get_base_health(rng);
+    let properties = self.get_properties(rng);
+    let abilities = self.get_abilities(&properties.0, 0..=1, rng);
+    let attacks = self.get_attacks(&properties.0, 0..=1, rng);
     Entity { id: Uuid::new_v4(), name: self.name, description: self.description, max_health, current_health: max_health,
-      abilities: self.get_abilities(properties.0), attacks: self.get_attacks(properties.0),
-      state: ALIVE, stacks: Vec::new(), attributes: properties.0, damage_resistance: properties.1 }
+      abilities, battle_abilities: Vec::new(), attacks,
+      auras: self.get_auras(0..=1),
+      state: ALIVE, stacks: Vec::new(), statuses: Vec::new(), modifiers: Vec::new(), shield: 0, evasion: self.evasion,
+      stats: self.get_stats(rng), damaged_this_turn: false, last_attacker: None, summoner: None,
+      ability_states: HashMap::new(), attributes: properties.0, damage_resistance: properties.1,
+      level: 1, experience: 0, growth: self.growth, equipment: Vec::new() }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::{SeedableRng, rngs::StdRng};
+
+  #[test]
+  fn certain_chances_are_not_rolled() {
+    let mut rng = StdRng::seed_from_u64(0);
+    assert_eq!(roll(&mut rng, 100), None);
+    assert_eq!(roll(&mut rng, u8::MAX), None);
+  }
+
+  #[test]
+  fn rolls_succeed_at_or_below_the_chance() {
+    let mut rng = StdRng::seed_from_u64(0);
+    for chance in [0, 1, 30, 99] {
+      for _ in 0..100 {
+        let (success, rolled) = roll(&mut rng, chance).unwrap();
+        assert!((1..=100).contains(&rolled));
+        assert_eq!(success, rolled <= chance);
+      }
+    }
+  }
+
+  #[test]
+  fn same_seed_rolls_the_same() {
+    let rolls = |seed| {
+      let mut rng = StdRng::seed_from_u64(seed);
+      (0..50).map(|_| roll(&mut rng, 50)).collect::<Vec<_>>()
+    };
+    assert_eq!(rolls(42), rolls(42));
+    assert_ne!(rolls(42), rolls(43));
+  }
+
+  fn slime() -> Entity {
+    templates::slime().build_with(&mut StdRng::seed_from_u64(0))
+  }
+
+  #[test]
+  fn level_up_grows_health() {
+    let mut entity = slime();
+    let (max_health, current_health) = (entity.max_health, entity.current_health);
+    entity.level_up(&mut StdRng::seed_from_u64(0), Locale::English);
+
+    assert_eq!(entity.level, 2);
+    assert_eq!(entity.max_health, max_health + entity.growth.health_per_level);
+    assert_eq!(entity.current_health, current_health + entity.growth.health_per_level);
+  }
+
+  #[test]
+  fn experience_carries_over_levels() {
+    let mut entity = slime();
+    entity.gain_experience(experience_to_next(1) + experience_to_next(2) + 5, &mut StdRng::seed_from_u64(0), Locale::English);
+
+    assert_eq!(entity.level, 3);
+    assert_eq!(entity.experience, 5);
+  }
+
+  #[test]
+  fn experience_stops_at_max_level() {
+    let mut entity = slime();
+    entity.gain_experience(u32::MAX / 2, &mut StdRng::seed_from_u64(0), Locale::English);
+    assert_eq!(entity.level, MAX_LEVEL);
+    assert_eq!(entity.experience, 0);
+
+    entity.gain_experience(100, &mut StdRng::seed_from_u64(0), Locale::English);
+    assert_eq!(entity.level, MAX_LEVEL);
+    assert_eq!(entity.experience, 0);
+  }
+
+  #[test]
+  fn stacks_are_only_resisted_once() {
+    let mut entity = slime();
+    entity.max_health = 20;
+    entity.current_health = 20;
+    entity.add_stacks(4, PHYSICAL, DamageSource::default(), &[], Locale::English);
+    let (_, stacks) = entity.stacks[0];
+
+    entity.tick_stacks(&[], Locale::English);
+    assert_eq!(entity.current_health, 20 - stacks);
+    assert_eq!(entity.stacks, vec![(PHYSICAL, stacks - 1)]);
+  }
+
+  #[test]
+  fn evolving_keeps_what_was_learned() {
+    let mut entity = slime();
+    entity.level = 5;
+    entity.attacks = vec![&templates::ENGULF];
+    entity.abilities = vec![&templates::HARDEN];
+    let evolved = templates::giant_slime().evolve(&entity, &mut StdRng::seed_from_u64(0));
+
+    assert_eq!(evolved.attacks[0].name, templates::ENGULF.name);
+    assert_eq!(evolved.abilities[0].name, templates::HARDEN.name);
+    // 6 more base health for a Giant Slime and 1 more per level past the first
+    assert_eq!(evolved.max_health, entity.max_health + 6 + 4);
+    assert_eq!(evolved.current_health, evolved.max_health);
+  }
+
+  #[test]
+  fn evolving_at_level_zero() {
+    let mut entity = slime();
+    entity.level = 0;
+    let evolved = templates::giant_slime().evolve(&entity, &mut StdRng::seed_from_u64(0));
+    assert_eq!(evolved.max_health, entity.max_health + 6);
   }
 }
@@ -1,21 +1,65 @@
-use super::DamageType;
+use super::{DamageType, Entity};
+use super::attributes::Trait;
+use super::state::StatusEffect;
+use super::summon::Summon;
+use super::stats::Modifier;
+use super::dmg_resistance::DamageResistance;
 pub mod trigger_target;
 use trigger_target::{ITSELF, ALLY, OPPONENT, CURRENT_TEAM, ALL_BUT_ALLY, ALL_BUT_SELF, ALL};
 use std::fmt::Display;
+use crate::locale::{Locale, Localized, plural};
+use crate::tr;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Ability {
   pub name: &'static str,
   pub trigger: AbilityTrigger,
-  pub effect: AbilityEffect,
-  pub required_traits: u8,
-  pub forbidden_traits: u8,
-  pub probability: f32
+  /// Applied in order whenever the ability goes off
+  pub effects: &'static [AbilityEffect],
+  pub required_traits: &'static [Trait],
+  pub forbidden_traits: &'static [Trait],
+  pub probability: f32,
+  pub min_level: u8,
+  /// Turns of its team the ability has to wait before it can go off again
+  pub cooldown: u8,
+  /// How many times the ability can go off at all, `None` for no limit
+  pub charges: Option<u8>,
+  /// Chance out of 100 of going off whenever it is triggered, unlike `probability` which is
+  /// the chance of a creature learning the ability
+  pub proc_chance: u8
+}
+
+/// Remaining cooldown and spent charges of an ability on a single entity
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AbilityState {
+  pub cooldown: u8,
+  pub charges_used: u8
+}
+
+impl AbilityState {
+  pub fn is_ready(&self, ability: &Ability) -> bool {
+    self.cooldown == 0 && ability.charges.is_none_or(|charges| self.charges_used < charges)
+  }
 }
 
 impl Display for Ability {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "  \\# `{}`\n    {}, {}.\n", self.name, self.trigger, self.effect)
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Ability> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(ability, locale) = *self;
+    let mut limits = String::new();
+    if ability.cooldown > 0 {
+      limits += &format!(" ⏳{}", ability.cooldown);
+    }
+    if let Some(charges) = ability.charges {
+      limits += &format!(" 🔋{}", charges);
+    }
+    write!(f, "  \\# `{}`{}\n    {}, {}.\n", ability.name, limits, Localized(&ability.trigger, locale),
+      describe_effects(ability.effects, locale))
   }
 }
 
@@ -23,7 +67,9 @@ impl Display for Ability {
 pub struct AbilityTrigger {
   pub t: AbilityTriggerType,
   pub source: u8,
-  pub target: u8
+  pub target: u8,
+  /// Every condition has to be met on top of the masks for the trigger to go off
+  pub conditions: &'static [TriggerCondition]
 }
 
 impl AbilityTrigger {
@@ -33,19 +79,83 @@ impl AbilityTrigger {
   }
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub enum TriggerCondition {
+  /// The creature holding the ability is under this percentage of its max health
+  HealthBelow(u8),
+  /// The damage knocked out its target
+  Kills,
+  /// The damage is the first its target took this turn
+  FirstHit,
+  /// The damage dealt is strictly above this amount
+  DamageAbove(u8)
+}
+
+/// What happened when a trigger went off, checked against its conditions
+#[derive(Debug, Clone, Copy)]
+pub struct TriggerEvent {
+  /// ID of the entity holding the ability, which its effects call `This`
+  pub source: u8,
+  /// ID of the other entity involved, which its effects call `TriggerTarget`
+  pub target: u8,
+  pub damage: u8,
+  pub kills: bool,
+  pub first_hit: bool
+}
+
+impl TriggerCondition {
+  pub fn is_met(&self, holder: &Entity, event: &TriggerEvent) -> bool {
+    match self {
+      TriggerCondition::HealthBelow(percent) =>
+        (holder.current_health as u32) * 100 < (holder.max_health as u32) * (*percent as u32),
+      TriggerCondition::Kills => event.kills,
+      TriggerCondition::FirstHit => event.first_hit,
+      TriggerCondition::DamageAbove(amount) => event.damage > *amount
+    }
+  }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum AbilityTriggerType {
   AnyDamage,
   Damage(DamageType),
-  Heal
+  Heal,
+  /// The creature holding the ability fainted, the source being whoever knocked it out
+  OnFaint,
+  /// An ally of the creature holding the ability fainted
+  OnAllyFaint,
+  /// The creature holding the ability knocked out the target
+  OnKill,
+  /// The turn of the creature holding the ability starts
+  TurnStart,
+  /// The turn of the creature holding the ability ends
+  TurnEnd,
+  /// The creature holding the ability joined the scene
+  EnterScene
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct AbilityEffect {
   pub target: AbilityEffectTarget,
-  pub damage: u8,
-  pub t: DamageType
+  pub kind: EffectKind
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub enum EffectKind {
+  Damage(DamageType, u8),
+  /// Stacks dealt at the start of the target's turns, whether the damage type stacks on its own or not
+  Stacks(DamageType, u8),
+  Heal(u8),
+  /// Percentage of max health a fainted target is brought back with, does nothing to conscious ones
+  Revive(u8),
+  /// A buff, a debuff or a resistance change lasting this many of the target's turns
+  Modify(Modifier, u8),
+  Status(StatusEffect),
+  /// Entities joining the team of the creature holding the ability, whatever the effect's target
+  Summon(Summon)
 }
 
 #[allow(dead_code)]
@@ -61,73 +171,151 @@ pub enum AbilityEffectTarget {
 
 impl Display for AbilityTrigger {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let mut result = String::new();
-    let mut source_is_this = false;
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, AbilityTrigger> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(trigger, locale) = *self;
+    let mut result = describe_masks(trigger, locale);
 
-    let source = self.source & ALL;
-    let target = self.target & ALL;
+    for condition in trigger.conditions.iter() {
+      result += &Localized(condition, locale).to_string();
+    }
 
-    result += match source {
-      ITSELF => {
-        source_is_this = true;
-        "Upon "
-      },
-      ALLY => "Whenever an ally ",
-      OPPONENT => "Whenever an opponent ",
-      CURRENT_TEAM => "Whenever any creature on this team ",
-      ALL_BUT_ALLY => "Whenever itself or an opponent ",
-      ALL_BUT_SELF => "Whenever an ally or an opponent ",
-      ALL | _ => "Whenever any creature "
-    };
+    write!(f, "{}", result)
+  }
+}
 
+/// Who triggers the ability on whom, without its conditions
+fn describe_masks(trigger: &AbilityTrigger, locale: Locale) -> String {
+  let source = trigger.source & ALL;
+  let target = trigger.target & ALL;
+  let source_is_this = source == ITSELF;
 
-    if source_is_this {
-      match self.t {
-        AbilityTriggerType::AnyDamage => result += "dealing damage to ",
-        AbilityTriggerType::Damage(dt) => result += &format!("dealing {} damage to ", dt),
-        AbilityTriggerType::Heal => result += "healing "
-      }
-    }
-    else {
-      match self.t {
-        AbilityTriggerType::AnyDamage => result += "deals damage to ",
-        AbilityTriggerType::Damage(dt) => result += &format!("deals {} damage to ", dt),
-        AbilityTriggerType::Heal => result += "heals "
-      }
-    }
+  // Some languages say "healing itself" in a way that cannot be pieced together
+  if source_is_this && target == ITSELF && trigger.t == AbilityTriggerType::Heal {
+    return locale.get("trigger.heal_itself").to_string();
+  }
 
+  // Fainting and turn phases are described from the point of view of the creature holding the ability
+  match trigger.t {
+    AbilityTriggerType::OnFaint => return locale.get("trigger.on_faint").to_string(),
+    AbilityTriggerType::OnAllyFaint => return locale.get("trigger.on_ally_faint").to_string(),
+    AbilityTriggerType::OnKill => return tr!(locale, "trigger.on_kill", match target {
+      ITSELF => locale.get("trigger.target.itself"),
+      ALLY => locale.get("trigger.target.ally"),
+      OPPONENT => locale.get("trigger.target.opponent"),
+      _ => locale.get("trigger.target.all")
+    }),
+    AbilityTriggerType::TurnStart => return locale.get("trigger.turn_start").to_string(),
+    AbilityTriggerType::TurnEnd => return locale.get("trigger.turn_end").to_string(),
+    AbilityTriggerType::EnterScene => return locale.get("trigger.enter_scene").to_string(),
+    _ => {}
+  }
 
-    result += match target {
-      ITSELF => {
-        if source_is_this { "itself" }
-        else { "this creature" }
-      },
-      ALLY => "an ally",
-      OPPONENT => "an opponent",
-      CURRENT_TEAM => "any creature in this team",
-      ALL_BUT_ALLY => "this creature or an ally",
-      ALL_BUT_SELF => "an ally or an opponent",
-      ALL | _ => "any creature"
-    };
+  let mut result = String::from(match source {
+    ITSELF => locale.get("trigger.source.itself"),
+    ALLY => locale.get("trigger.source.ally"),
+    OPPONENT => locale.get("trigger.source.opponent"),
+    CURRENT_TEAM => locale.get("trigger.source.team"),
+    ALL_BUT_ALLY => locale.get("trigger.source.all_but_ally"),
+    ALL_BUT_SELF => locale.get("trigger.source.all_but_self"),
+    _ => locale.get("trigger.source.all")
+  });
 
+  result += &match (source_is_this, trigger.t) {
+    (true, AbilityTriggerType::AnyDamage) => tr!(locale, "trigger.dealing_damage"),
+    (true, AbilityTriggerType::Damage(dt)) => tr!(locale, "trigger.dealing_typed_damage", Localized(&dt, locale)),
+    (true, AbilityTriggerType::Heal) => tr!(locale, "trigger.healing"),
+    (false, AbilityTriggerType::AnyDamage) => tr!(locale, "trigger.deals_damage"),
+    (false, AbilityTriggerType::Damage(dt)) => tr!(locale, "trigger.deals_typed_damage", Localized(&dt, locale)),
+    (false, AbilityTriggerType::Heal) => tr!(locale, "trigger.heals"),
+    _ => unreachable!("fainting and turn phase triggers are described above")
+  };
 
-    write!(f, "{}", result)
+  result += match target {
+    ITSELF => {
+      if source_is_this { locale.get("trigger.target.itself") }
+      else { locale.get("trigger.target.this") }
+    },
+    ALLY => locale.get("trigger.target.ally"),
+    OPPONENT => locale.get("trigger.target.opponent"),
+    CURRENT_TEAM => locale.get("trigger.target.team"),
+    ALL_BUT_ALLY => locale.get("trigger.target.all_but_ally"),
+    ALL_BUT_SELF => locale.get("trigger.target.all_but_self"),
+    _ => locale.get("trigger.target.all")
+  };
+
+  result
+}
+
+impl Display for Localized<'_, TriggerCondition> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(condition, locale) = *self;
+    let result = match condition {
+      TriggerCondition::HealthBelow(percent) => tr!(locale, "condition.health_below", percent),
+      TriggerCondition::Kills => tr!(locale, "condition.kills"),
+      TriggerCondition::FirstHit => tr!(locale, "condition.first_hit"),
+      TriggerCondition::DamageAbove(amount) => tr!(locale, "condition.damage_above", amount)
+    };
+    write!(f, " {}", result)
   }
 }
 
 impl Display for AbilityEffect {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let target_str: &str;
-
-    match self.target {
-      AbilityEffectTarget::This => target_str = "itself",
-      AbilityEffectTarget::TriggerTarget => target_str = "that targeted creature",
-      AbilityEffectTarget::AnyAlly => target_str = "a random ally",
-      AbilityEffectTarget::AnyOpponent => target_str = "a random opponent",
-      AbilityEffectTarget::AllAlly => target_str = "all allies",
-      AbilityEffectTarget::AllOpponent => target_str = "all opponents"
-    }
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, AbilityEffect> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(effect, locale) = *self;
+    let target = match effect.target {
+      AbilityEffectTarget::This => locale.get("effect.target.itself"),
+      AbilityEffectTarget::TriggerTarget => locale.get("effect.target.trigger_target"),
+      AbilityEffectTarget::AnyAlly => locale.get("effect.target.any_ally"),
+      AbilityEffectTarget::AnyOpponent => locale.get("effect.target.any_opponent"),
+      AbilityEffectTarget::AllAlly => locale.get("effect.target.all_allies"),
+      AbilityEffectTarget::AllOpponent => locale.get("effect.target.all_opponents")
+    };
+
+    let result = match effect.kind {
+      EffectKind::Damage(t, amount) => tr!(locale, plural(amount, "effect.deal.one", "effect.deal.other"),
+        amount, Localized(&t, locale), target),
+      EffectKind::Stacks(t, amount) => tr!(locale, plural(amount, "effect.stacks.one", "effect.stacks.other"),
+        amount, Localized(&t, locale), target),
+      EffectKind::Heal(amount) => tr!(locale, "effect.heal", target, amount),
+      EffectKind::Revive(percent) => tr!(locale, "effect.revive", target, percent),
+      EffectKind::Modify(Modifier::Stat(stat, amount), turns) =>
+        tr!(locale, plural(turns, "effect.modify_stat.one", "effect.modify_stat.other"),
+          target, format!("{:+}", amount), Localized(&stat, locale), turns),
+      EffectKind::Modify(Modifier::Resistance(t, resistance), turns) => {
+        let resistance = match resistance {
+          DamageResistance::WEAKNESS => locale.get("resistance.weakness"),
+          DamageResistance::NEUTRAL => locale.get("resistance.neutral"),
+          DamageResistance::RESISTANCE => locale.get("resistance.resistance"),
+          DamageResistance::IMMUNITY => locale.get("resistance.immunity")
+        };
+        tr!(locale, plural(turns, "effect.modify_resistance.one", "effect.modify_resistance.other"),
+          target, resistance, Localized(&t, locale), turns)
+      },
+      EffectKind::Status(status) => tr!(locale, plural(status.turns, "effect.status.one", "effect.status.other"),
+        Localized(&status.status, locale), target, status.turns, status.chance),
+      EffectKind::Summon(summon) => Localized(&summon, locale).to_string()
+    };
+
+    write!(f, "{}", result)
+  }
+}
 
-    write!(f, "deal {} {} damage to {}", self.damage, self.t, target_str)
+/// Every effect of an ability in a single sentence
+pub fn describe_effects(effects: &[AbilityEffect], locale: Locale) -> String {
+  let parts: Vec<String> = effects.iter().map(|effect| Localized(effect, locale).to_string()).collect();
+  match parts.split_last() {
+    Some((last, rest)) if !rest.is_empty() => tr!(locale, "effect.and", rest.join(", "), last),
+    _ => parts.concat()
   }
 }
@@ -1,5 +1,13 @@
 use std::fmt::Display;
 use super::DamageType;
+use super::attributes::Trait;
+use super::state::StatusEffect;
+use super::summon::Summon;
+use crate::locale::{Locale, Localized, plural};
+use crate::tr;
+
+/// Percentage of an attack's damage dealt to the neighbours of a splash attack's target
+pub const SPLASH_DAMAGE_PERCENT: u8 = 50;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Attack {
@@ -7,13 +15,80 @@ pub struct Attack {
   pub cost: u8,
   pub damage: u8,
   pub t: DamageType,
-  pub required_traits: u8,
-  pub forbidden_traits: u8,
-  pub prob: f32
+  pub required_traits: &'static [Trait],
+  pub forbidden_traits: &'static [Trait],
+  pub prob: f32,
+  pub min_level: u8,
+  /// Chance out of 100 of hitting the target, unlike `prob` which is the chance of a creature
+  /// learning the attack
+  pub hit_chance: u8,
+  /// Chance out of 100 of a hit being critical, multiplying the damage by the scene's ruleset
+  pub crit_chance: u8,
+  /// Status inflicted on the target when the attack lands
+  pub status: Option<StatusEffect>,
+  pub shape: AttackShape,
+  /// Entities joining the attacker's team once the attack is used, whether it hits or not
+  pub summon: Option<Summon>
+}
+
+/// Which entities an attack hits, every one of them rolling to be hit and triggering abilities on its own
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttackShape {
+  /// Only the chosen target
+  Single,
+  /// Every opponent still standing, whatever the chosen target
+  AllOpponents,
+  /// Every ally still standing, whatever the chosen target
+  AllAllies,
+  /// This many different opponents picked at random
+  Random(u8),
+  /// The chosen target, and for a fraction of the damage the creatures registered next to it on its team
+  Splash
+}
+
+/// Damage dealt by a critical hit
+pub fn crit_damage(damage: u8, multiplier: f32) -> u8 {
+  (damage as f32 * multiplier).round().min(u8::MAX as f32) as u8
+}
+
+/// Damage dealt to the neighbours of a splash attack's target
+pub fn splash_damage(damage: u8) -> u8 {
+  ((damage as u16 * SPLASH_DAMAGE_PERCENT as u16) / 100) as u8
 }
 
 impl Display for Attack {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "  \\> `{}` {} 🔵  {} {}\n", self.name, self.cost, self.damage, self.t.icon)
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Attack> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(attack, locale) = *self;
+    let mut status = String::new();
+    if attack.hit_chance < 100 {
+      status += &format!("  🎯{}%", attack.hit_chance);
+    }
+    if attack.crit_chance > 0 {
+      status += &format!("  💥{}%", attack.crit_chance);
+    }
+    if let Some(effect) = attack.status {
+      status += &format!("  {}{} {}%", effect.status.icon(), effect.turns, effect.chance);
+    }
+    let shape = match attack.shape {
+      AttackShape::Single => String::new(),
+      AttackShape::AllOpponents => format!("  ⤳ {}", locale.get("shape.all_opponents")),
+      AttackShape::AllAllies => format!("  ⤳ {}", locale.get("shape.all_allies")),
+      AttackShape::Random(amount) => format!("  ⤳ {}",
+        tr!(locale, plural(amount, "shape.random.one", "shape.random.other"), amount)),
+      AttackShape::Splash => format!("  ⤳ {}", tr!(locale, "shape.splash", SPLASH_DAMAGE_PERCENT))
+    };
+    let summon = match attack.summon {
+      Some(summon) => format!("  ✨ {}", Localized(&summon, locale)),
+      None => String::new()
+    };
+    writeln!(f, "  \\> `{}` {} 🔵  {} {}{}{}{}", attack.name, attack.cost, attack.damage, attack.t.icon, status, shape,
+      summon)
   }
 }
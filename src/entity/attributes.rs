@@ -1,10 +1,134 @@
-#[allow(dead_code)]
-pub const NONE: u8 = 0;
-#[allow(dead_code)]
-pub const PHYSICAL: u8 = 1;
-#[allow(dead_code)]
-pub const POISONOUS: u8 = 2;
-#[allow(dead_code)]
-pub const ACIDIC: u8 = 4;
-#[allow(dead_code)]
-pub const VAMPIRIC: u8 = 8;
+use std::fmt::Display;
+
+use crate::locale::{Locale, Localized};
+
+/// Something a creature is made of, deciding which variants, attacks and abilities it can roll
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Trait {
+  pub name: &'static str,
+  pub description: &'static str,
+  pub icon: &'static str
+}
+
+pub const PHYSICAL: Trait = Trait { name: "physical", description: "Has a body solid enough to hit things with.",
+  icon: ":muscle:" };
+pub const POISONOUS: Trait = Trait { name: "poisonous", description: "Its body is laced with venom.",
+  icon: ":skull_crossbones:" };
+pub const ACIDIC: Trait = Trait { name: "acidic", description: "Eats through whatever it touches.",
+  icon: ":test_tube:" };
+pub const VAMPIRIC: Trait = Trait { name: "vampiric", description: "Feeds on the life of others.",
+  icon: ":vampire:" };
+
+/// Every trait known to the bot
+pub static TRAITS: [Trait; 4] = [PHYSICAL, POISONOUS, ACIDIC, VAMPIRIC];
+
+/// Bits traits used to be saved as before they had names
+const LEGACY_BITS: [(u8, Trait); 4] = [(1, PHYSICAL), (2, POISONOUS), (4, ACIDIC), (8, VAMPIRIC)];
+
+pub fn get(name: &str) -> Option<Trait> {
+  TRAITS.iter().find(|t| t.name.eq_ignore_ascii_case(name)).copied()
+}
+
+impl Trait {
+  pub fn localized_name(&self, locale: Locale) -> &'static str {
+    match self.name {
+      "physical" => locale.get("trait.physical"),
+      "poisonous" => locale.get("trait.poisonous"),
+      "acidic" => locale.get("trait.acidic"),
+      "vampiric" => locale.get("trait.vampiric"),
+      name => name
+    }
+  }
+
+  pub fn localized_description(&self, locale: Locale) -> &'static str {
+    match self.name {
+      "physical" => locale.get("trait.physical.description"),
+      "poisonous" => locale.get("trait.poisonous.description"),
+      "acidic" => locale.get("trait.acidic.description"),
+      "vampiric" => locale.get("trait.vampiric.description"),
+      _ => self.description
+    }
+  }
+}
+
+impl Display for Trait {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Trait> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "  \\~ {} `{}` {}", self.0.icon, self.0.localized_name(self.1), self.0.localized_description(self.1))
+  }
+}
+
+/// Any number of traits, each one at most once
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Traits(Vec<Trait>);
+
+impl Traits {
+  pub fn new() -> Self {
+    Traits(Vec::new())
+  }
+
+  /// Traits named in `names`, names of traits that do not exist anymore are ignored
+  pub fn from_names(names: &[String]) -> Self {
+    Traits::from(names.iter().filter_map(|name| get(name)).collect::<Vec<Trait>>().as_slice())
+  }
+
+  /// Traits stored in the old `u8` bitmask format
+  pub fn from_bitmask(bits: u8) -> Self {
+    let traits: Vec<Trait> = LEGACY_BITS.iter().filter(|(bit, _)| bits & bit != 0).map(|(_, t)| *t).collect();
+    Traits::from(traits.as_slice())
+  }
+
+  pub fn names(&self) -> Vec<String> {
+    self.0.iter().map(|t| t.name.to_string()).collect()
+  }
+
+  pub fn contains(&self, t: Trait) -> bool {
+    self.0.contains(&t)
+  }
+
+  pub fn contains_all(&self, traits: &[Trait]) -> bool {
+    traits.iter().all(|t| self.contains(*t))
+  }
+
+  pub fn contains_any(&self, traits: &[Trait]) -> bool {
+    traits.iter().any(|t| self.contains(*t))
+  }
+
+  pub fn insert(&mut self, t: Trait) {
+    if !self.contains(t) {
+      self.0.push(t);
+    }
+  }
+
+  pub fn extend(&mut self, traits: &[Trait]) {
+    for t in traits.iter() {
+      self.insert(*t);
+    }
+  }
+
+  /// Traits found in both sets
+  pub fn intersection(&self, other: &Traits) -> Traits {
+    Traits(self.0.iter().filter(|t| other.contains(**t)).copied().collect())
+  }
+
+  pub fn as_slice(&self) -> &[Trait] {
+    &self.0
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &Trait> {
+    self.0.iter()
+  }
+}
+
+impl From<&[Trait]> for Traits {
+  fn from(traits: &[Trait]) -> Self {
+    let mut result = Traits::new();
+    result.extend(traits);
+    result
+  }
+}
@@ -0,0 +1,85 @@
+use std::fmt::Display;
+
+use super::DamageType;
+use super::dmg_resistance::DamageResistance;
+use super::ability::trigger_target::{ITSELF, ALLY, OPPONENT, CURRENT_TEAM, ALL};
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+/// A passive effect applying to every entity matching `target` for as long as its owner is alive
+#[derive(Debug, Clone, Copy)]
+pub struct Aura {
+  pub name: &'static str,
+  /// Who is affected, relative to the owner, using the same masks as ability triggers
+  pub target: u8,
+  pub effect: AuraEffect,
+  pub min_level: u8
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub enum AuraEffect {
+  /// Damage of a type is lowered by a flat amount after resistances
+  DamageReduction(DamageType, u8),
+  /// Resistance to a damage type is replaced, whatever the entity or its equipment has
+  Resistance(DamageType, DamageResistance)
+}
+
+impl Aura {
+  /// Whether the aura of the entity `owner` applies to the entity `other`, both being scene IDs
+  pub fn affects(&self, owner: u8, other: u8) -> bool {
+    let relation = if owner == other { ITSELF }
+      else if owner & 3 == other & 3 { ALLY }
+      else { OPPONENT };
+
+    self.target & relation != 0
+  }
+
+  pub fn damage_reduction(&self, t: DamageType) -> u8 {
+    match self.effect {
+      AuraEffect::DamageReduction(dt, amount) if dt == t => amount,
+      _ => 0
+    }
+  }
+
+  pub fn resistance(&self, t: DamageType) -> Option<DamageResistance> {
+    match self.effect {
+      AuraEffect::Resistance(dt, r) if dt == t => Some(r),
+      _ => None
+    }
+  }
+}
+
+impl Display for Aura {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Aura> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(aura, locale) = *self;
+    let target = match aura.target & ALL {
+      ITSELF => locale.get("aura.target.itself"),
+      ALLY => locale.get("aura.target.allies"),
+      OPPONENT => locale.get("aura.target.opponents"),
+      CURRENT_TEAM => locale.get("aura.target.team"),
+      _ => locale.get("aura.target.all")
+    };
+
+    let effect = match aura.effect {
+      AuraEffect::DamageReduction(t, amount) => tr!(locale, "aura.damage_reduction", amount, Localized(&t, locale), target),
+      AuraEffect::Resistance(t, r) => {
+        let resistance = match r {
+          DamageResistance::WEAKNESS => locale.get("resistance.weakness"),
+          DamageResistance::NEUTRAL => locale.get("resistance.neutral"),
+          DamageResistance::RESISTANCE => locale.get("resistance.resistance"),
+          DamageResistance::IMMUNITY => locale.get("resistance.immunity")
+        };
+        tr!(locale, "aura.resistance", Localized(&t, locale), resistance, target)
+      }
+    };
+
+    writeln!(f, "  \\@ `{}`\n    {}.", aura.name, effect)
+  }
+}
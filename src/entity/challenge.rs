@@ -0,0 +1,226 @@
+use std::fmt::Display;
+
+use super::{Entity, EntityBuilder, Attack, Ability, templates};
+use super::ability::{AbilityEffectTarget, EffectKind};
+use super::attack::{AttackShape, SPLASH_DAMAGE_PERCENT};
+use super::attributes::Traits;
+use super::dmg_type::DAMAGE_TYPES;
+use super::dmg_resistance::DamageResistance::NEUTRAL;
+use super::stats::{DamageRules, Modifier, STAT_SCALE};
+use super::summon::Summon;
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+/// Opponents an attack or ability hitting a whole team is assumed to reach
+pub const EXPECTED_OPPONENTS: f32 = 2.0;
+/// Smallest resistance multiplier used when rating durability, so that immunities count as a lot but not infinitely
+const MIN_MULTIPLIER: f32 = 0.25;
+
+/// How strong a template is expected to be, averaged over every roll `EntityBuilder::build` can make
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChallengeRating {
+  /// Damage it takes to knock it out, its health scaled by its defense, evasion and resistances
+  pub durability: f32,
+  /// Damage its attacks are expected to deal per energy spent, scaled by its attack
+  pub offense: f32,
+  /// Damage, healing or hindrance its abilities are worth each time they are triggered
+  pub abilities: f32
+}
+
+impl ChallengeRating {
+  /// Single number to compare creatures by, the geometric mean of how much it takes and how much it deals
+  pub fn rating(&self) -> f32 {
+    (self.durability * (self.offense + self.abilities)).sqrt()
+  }
+
+  /// Rating of an entity of the template, accounting for the health and damage it gained since it was built
+  pub fn of(entity: &Entity) -> Option<Self> {
+    let builder = templates::get(entity.name)?;
+    let rating = builder.challenge_rating();
+    Some(ChallengeRating {
+      durability: rating.durability * entity.max_health as f32 / builder.mean_health(),
+      offense: rating.offense * entity.growth.damage_multiplier(entity.level),
+      abilities: rating.abilities
+    })
+  }
+}
+
+impl EntityBuilder {
+  /// Estimate the strength of the template at level 1, as an expected value over its variants, stats and
+  /// the attacks and abilities it might learn
+  pub fn challenge_rating(&self) -> ChallengeRating {
+    let mut result = ChallengeRating { durability: 0.0, offense: 0.0, abilities: 0.0 };
+    let variants = self.variant_properties.len().min(16);
+
+    // Variants roll independently, every combination is weighted by its chance of coming up
+    for rolled in 0..1u32 << variants {
+      let mut traits = Traits::from(self.base_properties.0);
+      let mut probability = 1.0;
+      for (index, (t, _, prob)) in self.variant_properties.iter().take(variants).enumerate() {
+        if rolled & 1 << index != 0 {
+          traits.extend(t);
+          probability *= prob;
+        }
+        else {
+          probability *= 1.0 - prob;
+        }
+      }
+      if probability <= 0.0 {
+        continue;
+      }
+
+      result.durability += probability * self.durability(&traits);
+      result.offense += probability * self.offense(&traits);
+      result.abilities += probability * self.ability_value(&traits);
+    }
+
+    result
+  }
+
+  /// Average of the health range
+  pub fn mean_health(&self) -> f32 {
+    (self.base_health.0 as f32 + self.base_health.1 as f32) / 2.0
+  }
+
+  fn mean_stat((min, max): (u8, u8)) -> f32 {
+    (min as f32 + max as f32) / 2.0
+  }
+
+  /// Damage needed to knock out a creature of the template with `traits`, against every damage type alike
+  fn durability(&self, traits: &Traits) -> f32 {
+    let resistances = self.get_resistances(traits);
+    let rules = DamageRules::STANDARD;
+    let resistance = DAMAGE_TYPES.iter()
+      .map(|t| 1.0 / rules.multiplier(*resistances.get(t).unwrap_or(&NEUTRAL)).max(MIN_MULTIPLIER))
+      .sum::<f32>() / DAMAGE_TYPES.len() as f32;
+    let defense = (STAT_SCALE + Self::mean_stat(self.defense)) / STAT_SCALE;
+    let evasion = 100.0 / (100.0 - self.evasion.min(90) as f32);
+
+    self.mean_health() * defense * evasion * resistance
+  }
+
+  /// Damage per energy of the attacks a creature with `traits` knows at level 1, weighted by their chance of being learned
+  fn offense(&self, traits: &Traits) -> f32 {
+    let attacks: Vec<&&Attack> = self.attacks.iter()
+      .filter(|attack| attack.min_level <= 1 && traits.contains_all(attack.required_traits)
+        && !traits.contains_any(attack.forbidden_traits))
+      .collect();
+    let weights: f32 = attacks.iter().map(|attack| attack.prob).sum();
+    if weights <= 0.0 {
+      return 0.0;
+    }
+
+    let attack_stat = (STAT_SCALE + Self::mean_stat(self.attack)) / STAT_SCALE;
+    attacks.iter().map(|attack| attack.prob * attack_value(attack)).sum::<f32>() / weights * attack_stat
+  }
+
+  /// Expected worth of the abilities a creature with `traits` knows at level 1
+  fn ability_value(&self, traits: &Traits) -> f32 {
+    self.abilities.iter()
+      .filter(|ability| ability.min_level <= 1 && traits.contains_all(ability.required_traits)
+        && !traits.contains_any(ability.forbidden_traits))
+      .map(|ability| ability.probability * ability_value(ability))
+      .sum()
+  }
+}
+
+/// Damage an attack is expected to deal per energy spent
+fn attack_value(attack: &Attack) -> f32 {
+  let targets = match attack.shape {
+    AttackShape::Single => 1.0,
+    AttackShape::AllOpponents => EXPECTED_OPPONENTS,
+    // Hitting your own team is not worth anything against opponents
+    AttackShape::AllAllies => 0.0,
+    AttackShape::Random(amount) => (amount as f32).min(EXPECTED_OPPONENTS),
+    AttackShape::Splash => 1.0 + (EXPECTED_OPPONENTS - 1.0) * SPLASH_DAMAGE_PERCENT as f32 / 100.0
+  };
+  let hit = attack.hit_chance as f32 / 100.0;
+  // Critical hits deal half again as much under the standard rules
+  let crit = 1.0 + attack.crit_chance as f32 / 100.0 * 0.5;
+  let status = attack.status.map_or(0.0, |effect| effect.turns as f32 * effect.chance as f32 / 100.0);
+  let summon = attack.summon.map_or(0.0, summon_value);
+
+  ((attack.damage as f32 * hit * crit + status) * targets + summon) / attack.cost.max(1) as f32
+}
+
+/// Summoned creatures are rated by their health alone, templates can summon themselves
+fn summon_value(summon: Summon) -> f32 {
+  templates::get(summon.template).map_or(0.0, |builder| builder.mean_health() * summon.count as f32 / 4.0)
+}
+
+/// Worth of an ability each time it is triggered, spread over its cooldown
+fn ability_value(ability: &Ability) -> f32 {
+  let effects: f32 = ability.effects.iter().map(|effect| {
+    let targets = match effect.target {
+      AbilityEffectTarget::AllAlly | AbilityEffectTarget::AllOpponent => EXPECTED_OPPONENTS,
+      _ => 1.0
+    };
+    let value = match effect.kind {
+      EffectKind::Damage(_, amount) | EffectKind::Stacks(_, amount) | EffectKind::Heal(amount) => amount as f32,
+      EffectKind::Revive(percent) => percent as f32 / 10.0,
+      EffectKind::Modify(Modifier::Stat(_, amount), turns) => amount.unsigned_abs() as f32 * turns as f32 / 2.0,
+      EffectKind::Modify(Modifier::Resistance(..), turns) => turns as f32 / 2.0,
+      EffectKind::Status(effect) => effect.turns as f32 * effect.chance as f32 / 100.0,
+      EffectKind::Summon(summon) => summon_value(summon)
+    };
+    value * targets
+  }).sum();
+
+  effects * ability.proc_chance as f32 / 100.0 / (1.0 + ability.cooldown as f32)
+}
+
+impl Display for ChallengeRating {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, ChallengeRating> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(rating, locale) = *self;
+    write!(f, "{}", tr!(locale, "challenge.rating", format!("{:.1}", rating.rating()), format!("{:.1}", rating.durability),
+      format!("{:.1}", rating.offense), format!("{:.1}", rating.abilities)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::{SeedableRng, rngs::StdRng};
+
+  fn rating(name: &str) -> f32 {
+    templates::get(name).unwrap().challenge_rating().rating()
+  }
+
+  #[test]
+  fn rating_is_the_geometric_mean() {
+    let rating = ChallengeRating { durability: 8.0, offense: 1.5, abilities: 0.5 };
+    assert_eq!(rating.rating(), 4.0);
+  }
+
+  #[test]
+  fn every_template_is_rated() {
+    for builder in templates::all() {
+      let rating = builder.challenge_rating();
+      assert!(rating.durability > 0.0 && rating.rating() > 0.0, "{} is not rated", builder.name);
+    }
+  }
+
+  #[test]
+  fn evolutions_rate_higher() {
+    assert!(rating("Giant Slime") > rating("Slime"));
+    assert!(rating("Bloated Leech") > rating("Leech"));
+  }
+
+  #[test]
+  fn entities_rate_by_what_they_gained() {
+    let builder = templates::slime();
+    let mut entity = builder.build_with(&mut StdRng::seed_from_u64(0));
+    entity.max_health = (builder.mean_health() * 2.0) as u8;
+    let base = builder.challenge_rating();
+    let rating = ChallengeRating::of(&entity).unwrap();
+
+    assert!((rating.durability - base.durability * 2.0).abs() < 0.01);
+    assert_eq!(rating.abilities, base.abilities);
+  }
+}
@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+use super::Entity;
+use super::dmg_type::{DamageType, POISON};
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+/// A single-use item spent from a team's inventory during its turn
+#[derive(Debug)]
+pub struct Consumable {
+  pub name: &'static str,
+  pub description: &'static str,
+  pub cost: u8,
+  pub heal: u8,
+  pub cure: Option<DamageType>,
+  pub shield: u8,
+  /// Percentage of max health a fainted entity is brought back with, 0 for items only usable on conscious entities
+  pub revive: u8
+}
+
+impl Consumable {
+  /// Whether reviving is all the item does, making it useless on a conscious entity
+  pub fn only_revives(&self) -> bool {
+    self.revive > 0 && self.heal == 0 && self.cure.is_none() && self.shield == 0
+  }
+
+  pub fn apply(&self, entity: &mut Entity, locale: Locale) -> String {
+    let mut result = String::new();
+
+    if self.revive > 0 && !entity.is_alive() {
+      let health = entity.revive(self.revive);
+      result += &tr!(locale, "heal.revived", entity.name, health);
+    }
+    if self.heal > 0 {
+      let healed_amt = entity.heal(self.heal);
+      result += &tr!(locale, "heal.healed", entity.name, healed_amt);
+    }
+    if let Some(t) = self.cure {
+      let cleared = entity.clear_stacks(t);
+      result += &tr!(locale, "item.cured", entity.name, cleared, Localized(&t, locale));
+    }
+    if self.shield > 0 {
+      let shield = entity.add_shield(self.shield);
+      result += &tr!(locale, "item.shielded", entity.name, shield);
+    }
+
+    result
+  }
+}
+
+impl Display for Consumable {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Consumable> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(item, locale) = *self;
+    let mut effects = Vec::new();
+    if item.revive > 0 {
+      effects.push(tr!(locale, "item.effect.revive", item.revive));
+    }
+    if item.heal > 0 {
+      effects.push(tr!(locale, "item.effect.heal", item.heal));
+    }
+    if let Some(t) = item.cure {
+      effects.push(tr!(locale, "item.effect.cure", Localized(&t, locale)));
+    }
+    if item.shield > 0 {
+      effects.push(tr!(locale, "item.effect.shield", item.shield));
+    }
+
+    writeln!(f, "  \\* `{}` {} 🔵  {}", item.name, item.cost, effects.join(", "))
+  }
+}
+
+pub static CONSUMABLES: [Consumable; 5] = [
+  Consumable { name: "Potion", description: "Tastes like cherries and regret.",
+    cost: 1, heal: 4, cure: None, shield: 0, revive: 0 },
+  Consumable { name: "Greater Potion", description: "Tastes like cherries and even more regret.",
+    cost: 2, heal: 8, cure: None, shield: 0, revive: 0 },
+  Consumable { name: "Antidote", description: "Draws the venom right out.",
+    cost: 1, heal: 0, cure: Some(POISON), shield: 0, revive: 0 },
+  Consumable { name: "Barrier", description: "A thin bubble that pops instead of you.",
+    cost: 1, heal: 0, cure: None, shield: 3, revive: 0 },
+  Consumable { name: "Phoenix Feather", description: "Still warm, somehow.",
+    cost: 3, heal: 0, cure: None, shield: 0, revive: 50 }
+];
+
+/// What every team gets when cards are dealt
+pub const STARTING_INVENTORY: [&str; 5] = ["Potion", "Potion", "Antidote", "Barrier", "Phoenix Feather"];
+
+pub fn get(name: &str) -> Option<&'static Consumable> {
+  CONSUMABLES.iter().find(|item| item.name.eq_ignore_ascii_case(name))
+}
@@ -1,5 +1,7 @@
-#[derive(Debug, Clone, Copy)]
-#[allow(dead_code)]
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[allow(dead_code, clippy::upper_case_acronyms)]
 pub enum DamageResistance {
   WEAKNESS,
   NEUTRAL,
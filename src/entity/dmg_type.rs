@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+use crate::locale::{Locale, Localized};
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct DamageType {
   pub name: &'static str,
@@ -9,7 +11,13 @@ pub struct DamageType {
 
 impl Display for DamageType {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "{} {}", self.name, self.icon)
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, DamageType> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} {}", self.0.localized_name(self.1), self.0.icon)
   }
 }
 
@@ -20,6 +28,8 @@ pub const VAMPIRIC: DamageType = DamageType { name: "vampiric", icon: ":drop_of_
 #[allow(dead_code)]
 pub const FIRE: DamageType = DamageType { name: "fire", icon: ":fire:", attributes: 5 };
 
+pub const DAMAGE_TYPES: [DamageType; 5] = [PHYSICAL, POISON, ACID, VAMPIRIC, FIRE];
+
 pub const DAMAGE_ON_HIT: i32 = 0b0001;
 pub const IS_VAMPIRIC: i32 = 0b0010;
 #[allow(dead_code)]
@@ -28,6 +38,21 @@ pub const IGNORE_SHIELD: i32 = 0b0100;
 pub const STACKS: i32 = 0b1000;
 
 impl DamageType {
+  pub fn from_name(name: &str) -> Option<DamageType> {
+    DAMAGE_TYPES.into_iter().find(|t| t.name == name)
+  }
+
+  pub fn localized_name(&self, locale: Locale) -> &'static str {
+    match self.name {
+      "physical" => locale.get("damage.physical"),
+      "poison" => locale.get("damage.poison"),
+      "acid" => locale.get("damage.acid"),
+      "vampiric" => locale.get("damage.vampiric"),
+      "fire" => locale.get("damage.fire"),
+      name => name
+    }
+  }
+
   pub fn damage_on_hit(&self) -> bool {
     self.attributes & DAMAGE_ON_HIT != 0
   }
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use super::{Attack, Ability, templates};
+use super::dmg_type::{DamageType, PHYSICAL, POISON, ACID};
+use super::dmg_resistance::DamageResistance::{self, RESISTANCE};
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Slot {
+  Head,
+  Body,
+  Charm
+}
+
+pub const SLOTS: [Slot; 3] = [Slot::Head, Slot::Body, Slot::Charm];
+
+impl Slot {
+  pub fn from_name(name: &str) -> Option<Slot> {
+    SLOTS.into_iter().find(|slot| slot.to_string().eq_ignore_ascii_case(name))
+  }
+
+  pub fn localized_name(&self, locale: Locale) -> &'static str {
+    match self {
+      Slot::Head => locale.get("slot.head"),
+      Slot::Body => locale.get("slot.body"),
+      Slot::Charm => locale.get("slot.charm")
+    }
+  }
+}
+
+impl Display for Slot {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Slot::Head => write!(f, "head"),
+      Slot::Body => write!(f, "body"),
+      Slot::Charm => write!(f, "charm")
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct Equipment {
+  pub name: &'static str,
+  pub description: &'static str,
+  pub slot: Slot,
+  pub max_health: u8,
+  pub damage_bonus: &'static [(DamageType, u8)],
+  pub resistances: &'static [(DamageType, DamageResistance)],
+  pub attacks: &'static [&'static Attack],
+  pub abilities: &'static [&'static Ability]
+}
+
+impl Equipment {
+  pub fn damage_bonus(&self, t: DamageType) -> u8 {
+    self.damage_bonus.iter().filter(|(dt, _)| *dt == t).map(|(_, bonus)| *bonus).sum()
+  }
+
+  pub fn resistance(&self, t: DamageType) -> Option<DamageResistance> {
+    self.resistances.iter().find(|(dt, _)| *dt == t).map(|(_, r)| *r)
+  }
+}
+
+impl Display for Equipment {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Equipment> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(item, locale) = *self;
+    let mut bonuses = Vec::new();
+    if item.max_health > 0 {
+      bonuses.push(format!("+{} ❤️", item.max_health));
+    }
+    for (t, bonus) in item.damage_bonus.iter() {
+      bonuses.push(format!("+{} {}", bonus, t.icon));
+    }
+    for (t, r) in item.resistances.iter() {
+      let key = match r {
+        DamageResistance::WEAKNESS => "equipment.weakness",
+        DamageResistance::NEUTRAL => "equipment.neutral",
+        DamageResistance::RESISTANCE => "equipment.resistance",
+        DamageResistance::IMMUNITY => "equipment.immunity"
+      };
+      bonuses.push(tr!(locale, key, t.icon));
+    }
+
+    writeln!(f, "  \\[ `{}` ({}) {}", item.name, item.slot.localized_name(locale), bonuses.join(", "))?;
+    for attack in item.attacks.iter() {
+      write!(f, "  {}", Localized(*attack, locale))?;
+    }
+    for ability in item.abilities.iter() {
+      write!(f, "  {}", Localized(*ability, locale))?;
+    }
+    Ok(())
+  }
+}
+
+pub static ITEMS: [Equipment; 4] = [
+  Equipment { name: "Iron Helmet", description: "Dented, but it still keeps teeth away from the skull.",
+    slot: Slot::Head, max_health: 2, damage_bonus: &[], resistances: &[(PHYSICAL, RESISTANCE)],
+    attacks: &[], abilities: &[] },
+  Equipment { name: "Leather Vest", description: "Smells like a wet cow.",
+    slot: Slot::Body, max_health: 3, damage_bonus: &[], resistances: &[],
+    attacks: &[], abilities: &[] },
+  Equipment { name: "Venom Fang", description: "Still dripping.",
+    slot: Slot::Charm, max_health: 0, damage_bonus: &[(POISON, 1)], resistances: &[(ACID, RESISTANCE)],
+    attacks: &[&templates::POISON_STING], abilities: &[] },
+  Equipment { name: "Echo Bell", description: "It rings a second time right after you shake it.",
+    slot: Slot::Charm, max_health: 0, damage_bonus: &[(PHYSICAL, 1)], resistances: &[],
+    attacks: &[], abilities: &[&templates::ECHO_STRIKE] }
+];
+
+pub fn get(name: &str) -> Option<&'static Equipment> {
+  ITEMS.iter().find(|item| item.name.eq_ignore_ascii_case(name))
+}
@@ -0,0 +1,73 @@
+use std::fmt::Display;
+
+use super::Entity;
+use super::attributes::Trait;
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy)]
+pub enum EvolutionCondition {
+  Level(u8),
+  Item(&'static str),
+  Traits(&'static [Trait])
+}
+
+impl EvolutionCondition {
+  pub fn is_met(&self, entity: &Entity, item: Option<&str>) -> bool {
+    match self {
+      EvolutionCondition::Level(level) => entity.level >= *level,
+      EvolutionCondition::Item(name) => item.is_some_and(|item| item.eq_ignore_ascii_case(name)),
+      EvolutionCondition::Traits(traits) => entity.attributes.contains_all(traits)
+    }
+  }
+}
+
+impl Display for EvolutionCondition {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, EvolutionCondition> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(condition, locale) = *self;
+    match condition {
+      EvolutionCondition::Level(level) => write!(f, "{}", tr!(locale, "evolution.level", level)),
+      EvolutionCondition::Item(name) => write!(f, "{}", tr!(locale, "evolution.item", name)),
+      EvolutionCondition::Traits(traits) => {
+        let names: Vec<String> = traits.iter().map(|t| format!("{} {}", t.icon, t.localized_name(locale))).collect();
+        write!(f, "{}", tr!(locale, "evolution.traits", names.join(", ")))
+      }
+    }
+  }
+}
+
+/// Turns an entity into the template named `into` once every condition is met
+#[derive(Debug, Clone)]
+pub struct Evolution {
+  pub into: &'static str,
+  pub conditions: Vec<EvolutionCondition>
+}
+
+impl Evolution {
+  pub fn is_met(&self, entity: &Entity, item: Option<&str>) -> bool {
+    self.conditions.iter().all(|condition| condition.is_met(entity, item))
+  }
+}
+
+impl Display for Evolution {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Evolution> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(evolution, locale) = *self;
+    let conditions: Vec<String> = evolution.conditions.iter()
+      .map(|condition| Localized(condition, locale).to_string()).collect();
+    writeln!(f, "  \\^ {}", tr!(locale, "evolution.into", evolution.into,
+      conditions.join(locale.get("evolution.and"))))
+  }
+}
@@ -0,0 +1,26 @@
+pub const MAX_LEVEL: u8 = 20;
+/// Experience earned by every creature taking part in a battle
+pub const BATTLE_EXPERIENCE: u32 = 10;
+/// Extra experience for creatures on the winning team
+pub const VICTORY_EXPERIENCE: u32 = 10;
+
+/// How a template's stats improve with each level
+#[derive(Debug, Clone, Copy)]
+pub struct Growth {
+  pub health_per_level: u8,
+  pub damage_per_level: f32
+}
+
+pub const NO_GROWTH: Growth = Growth { health_per_level: 0, damage_per_level: 0.0 };
+
+impl Growth {
+  /// Multiplier applied to attack damage at a given level
+  pub fn damage_multiplier(&self, level: u8) -> f32 {
+    1.0 + self.damage_per_level * (level.max(1) - 1) as f32
+  }
+}
+
+/// Experience needed to go from `level` to the next one
+pub fn experience_to_next(level: u8) -> u32 {
+  20 * level as u32
+}
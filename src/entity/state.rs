@@ -1 +1,68 @@
+use std::fmt::Display;
+
+use crate::locale::{Locale, Localized};
+
 pub const ALIVE: u8 = 1;
+
+/// Fire damage burning entities take at the start of each of their turns
+pub const BURN_DAMAGE: u8 = 1;
+/// Chance out of 100 for a confused entity to attack one of its allies instead of its target
+pub const CONFUSION_CHANCE: u8 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+  /// Cannot act
+  Stun,
+  /// Cannot act until it takes damage
+  Sleep,
+  /// Takes fire damage every turn
+  Burn,
+  /// Might attack an ally instead of its target
+  Confuse
+}
+
+impl Status {
+  pub fn icon(&self) -> &'static str {
+    match self {
+      Status::Stun => "💫",
+      Status::Sleep => "💤",
+      Status::Burn => "🔥",
+      Status::Confuse => "😵"
+    }
+  }
+
+  /// Whether the status keeps the entity from attacking or playing cards
+  pub fn prevents_action(&self) -> bool {
+    matches!(self, Status::Stun | Status::Sleep)
+  }
+
+  pub fn localized_name(&self, locale: Locale) -> &'static str {
+    match self {
+      Status::Stun => locale.get("status.stun"),
+      Status::Sleep => locale.get("status.sleep"),
+      Status::Burn => locale.get("status.burn"),
+      Status::Confuse => locale.get("status.confuse")
+    }
+  }
+}
+
+impl Display for Status {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Status> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} {}", self.0.localized_name(self.1), self.0.icon())
+  }
+}
+
+/// A status inflicted by an attack or an ability, lasting `turns` of the afflicted entity's turns
+#[derive(Debug, Clone, Copy)]
+pub struct StatusEffect {
+  pub status: Status,
+  pub turns: u8,
+  /// Chance out of 100 of inflicting it
+  pub chance: u8
+}
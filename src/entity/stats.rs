@@ -0,0 +1,192 @@
+use std::fmt::Display;
+use serde::{Serialize, Deserialize};
+
+use super::DamageType;
+use super::dmg_resistance::DamageResistance;
+use crate::locale::{Locale, Localized};
+
+/// Offense and defense points needed to double or halve damage
+pub const STAT_SCALE: f32 = 10.0;
+
+/// Offense and defense of an entity, rolled by `EntityBuilder` within its template's ranges
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+  pub attack: u8,
+  pub defense: u8
+}
+
+/// A stat temporary modifiers can change
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stat {
+  Attack,
+  Defense,
+  Evasion
+}
+
+impl Stat {
+  pub fn icon(&self) -> &'static str {
+    match self {
+      Stat::Attack => "⚔️",
+      Stat::Defense => "🧱",
+      Stat::Evasion => "💨"
+    }
+  }
+
+  pub fn localized_name(&self, locale: Locale) -> &'static str {
+    match self {
+      Stat::Attack => locale.get("stat.attack"),
+      Stat::Defense => locale.get("stat.defense"),
+      Stat::Evasion => locale.get("stat.evasion")
+    }
+  }
+}
+
+impl Display for Localized<'_, Stat> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} {}", self.0.localized_name(self.1), self.0.icon())
+  }
+}
+
+/// A change lasting a few of the entity's turns, buffs and debuffs only differing by the sign of the amount
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Modifier {
+  Stat(Stat, i8),
+  /// Resistance to a damage type replaced, whatever the entity or its equipment has
+  Resistance(DamageType, DamageResistance)
+}
+
+impl Modifier {
+  /// Short form shown next to the entity's health
+  pub fn icon(&self) -> String {
+    match self {
+      Modifier::Stat(stat, amount) => format!("{:+}{}", amount, stat.icon()),
+      Modifier::Resistance(t, resistance) => format!("{}{}", t.icon, match resistance {
+        DamageResistance::WEAKNESS => "⬇️",
+        DamageResistance::NEUTRAL => "⏺️",
+        DamageResistance::RESISTANCE => "⬆️",
+        DamageResistance::IMMUNITY => "🚫"
+      })
+    }
+  }
+}
+
+/// How the damage formula turns its result back into whole damage points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rounding {
+  Down,
+  #[default]
+  Nearest,
+  Up
+}
+
+impl Rounding {
+  pub fn apply(&self, amount: f32) -> u8 {
+    let rounded = match self {
+      Rounding::Down => amount.floor(),
+      Rounding::Nearest => amount.round(),
+      Rounding::Up => amount.ceil()
+    };
+    rounded.clamp(0.0, u8::MAX as f32) as u8
+  }
+}
+
+/// Tuning of the damage formula, set by the ruleset of the scene
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageRules {
+  pub rounding: Rounding,
+  pub weakness: f32,
+  pub resistance: f32,
+  pub immunity: f32
+}
+
+impl DamageRules {
+  pub const STANDARD: DamageRules = DamageRules { rounding: Rounding::Nearest, weakness: 1.5, resistance: 0.5, immunity: 0.0 };
+
+  /// Multiplier a resistance applies to damage
+  pub fn multiplier(&self, resistance: DamageResistance) -> f32 {
+    match resistance {
+      DamageResistance::WEAKNESS => self.weakness,
+      DamageResistance::NEUTRAL => 1.0,
+      DamageResistance::RESISTANCE => self.resistance,
+      DamageResistance::IMMUNITY => self.immunity
+    }
+  }
+}
+
+impl Default for DamageRules {
+  fn default() -> Self {
+    DamageRules::STANDARD
+  }
+}
+
+/// Who deals the damage, as far as the entity taking it is concerned
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DamageSource {
+  /// Attack stat of the entity dealing the damage, 0 for damage nobody deals directly such as stacks or burns
+  pub attack: u8,
+  pub rules: DamageRules
+}
+
+impl DamageSource {
+  /// Damage dealt by an entity with these stats
+  pub fn from_stats(stats: Stats, rules: DamageRules) -> Self {
+    DamageSource { attack: stats.attack, rules }
+  }
+}
+
+/// The damage formula, used for every hit, ability and stack before auras and shields step in:
+///
+/// `rounding(base × (STAT_SCALE + attack) / (STAT_SCALE + defense) × resistance)`
+///
+/// - `base` is the damage of the attack or ability, attacks being scaled by the attacker's level and equipment
+/// - `attack` is the attacker's offense, `defense` the target's, both being 0 leaves the damage as is
+/// - `resistance` is the target's multiplier for the damage type, from `DamageRules::multiplier`
+pub fn damage(base: u8, source: DamageSource, defense: u8, resistance: DamageResistance) -> u8 {
+  let stats = (STAT_SCALE + source.attack as f32) / (STAT_SCALE + defense as f32);
+  source.rules.rounding.apply(base as f32 * stats * source.rules.multiplier(resistance))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::entity::dmg_resistance::DamageResistance::{WEAKNESS, NEUTRAL, RESISTANCE, IMMUNITY};
+
+  fn source(attack: u8, rounding: Rounding) -> DamageSource {
+    DamageSource { attack, rules: DamageRules { rounding, ..DamageRules::STANDARD } }
+  }
+
+  #[test]
+  fn neutral_damage_without_stats_is_unchanged() {
+    assert_eq!(damage(4, DamageSource::default(), 0, NEUTRAL), 4);
+  }
+
+  #[test]
+  fn stat_scale_doubles_or_halves_damage() {
+    assert_eq!(damage(4, source(STAT_SCALE as u8, Rounding::Nearest), 0, NEUTRAL), 8);
+    assert_eq!(damage(4, source(0, Rounding::Nearest), STAT_SCALE as u8, NEUTRAL), 2);
+    assert_eq!(damage(4, source(5, Rounding::Nearest), 5, NEUTRAL), 4);
+  }
+
+  #[test]
+  fn resistances_multiply_damage() {
+    let standard = source(0, Rounding::Nearest);
+    assert_eq!(damage(4, standard, 0, WEAKNESS), 6);
+    assert_eq!(damage(4, standard, 0, RESISTANCE), 2);
+    assert_eq!(damage(4, standard, 0, IMMUNITY), 0);
+  }
+
+  #[test]
+  fn rounding_follows_the_rules() {
+    assert_eq!(damage(3, source(0, Rounding::Down), 0, WEAKNESS), 4);
+    assert_eq!(damage(3, source(0, Rounding::Nearest), 0, WEAKNESS), 5);
+    assert_eq!(damage(3, source(0, Rounding::Up), 0, RESISTANCE), 2);
+    assert_eq!(damage(3, source(0, Rounding::Down), 0, RESISTANCE), 1);
+  }
+
+  #[test]
+  fn damage_saturates() {
+    assert_eq!(damage(200, source(u8::MAX, Rounding::Nearest), 0, WEAKNESS), u8::MAX);
+  }
+}
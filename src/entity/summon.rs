@@ -0,0 +1,26 @@
+use std::fmt::Display;
+
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+/// New entities brought into the scene on the summoner's team, built from the template named `template`
+#[derive(Debug, Clone, Copy)]
+pub struct Summon {
+  pub template: &'static str,
+  pub count: u8,
+  /// How many entities of this template summoned by the same entity can be standing at once
+  pub max: u8
+}
+
+impl Display for Summon {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Summon> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(summon, locale) = *self;
+    write!(f, "{}", tr!(locale, "summon.description", summon.count, summon.template, summon.max))
+  }
+}
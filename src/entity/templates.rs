@@ -0,0 +1,241 @@
+use super::{EntityBuilder, Attack, Ability, AbilityTrigger, AbilityEffect, attributes};
+use super::ability::{AbilityTriggerType, AbilityEffectTarget, EffectKind, TriggerCondition, trigger_target::{ITSELF, ALLY, OPPONENT, ALL}};
+use super::dmg_type::{PHYSICAL, POISON, ACID, VAMPIRIC};
+use super::dmg_resistance::DamageResistance::{WEAKNESS, NEUTRAL, RESISTANCE, IMMUNITY};
+use super::stats::{Modifier, Stat};
+use super::aura::{Aura, AuraEffect};
+use super::attack::AttackShape;
+use super::summon::Summon;
+use super::growth::Growth;
+use super::state::{Status, StatusEffect};
+use super::evolution::{Evolution, EvolutionCondition};
+
+pub static POISON_TOUCH: Ability = Ability { name: "Poison Touch", trigger: AbilityTrigger { t: AbilityTriggerType::Damage(PHYSICAL),
+  source: ITSELF, target: OPPONENT, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::TriggerTarget, kind: EffectKind::Damage(POISON, 1) }],
+  required_traits: &[attributes::POISONOUS], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 0, charges: None, proc_chance: 100 };
+pub static ACID_TOUCH: Ability = Ability { name: "Acid Touch", trigger: AbilityTrigger { t: AbilityTriggerType::Damage(PHYSICAL),
+  source: ITSELF, target: OPPONENT, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::TriggerTarget, kind: EffectKind::Damage(ACID, 1) }],
+  required_traits: &[attributes::ACIDIC], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 0, charges: None, proc_chance: 100 };
+pub static ECHO_STRIKE: Ability = Ability { name: "Echo Strike", trigger: AbilityTrigger { t: AbilityTriggerType::AnyDamage,
+  source: ITSELF, target: ALLY | OPPONENT, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::TriggerTarget, kind: EffectKind::Damage(PHYSICAL, 1) }],
+  required_traits: &[], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 1, charges: None, proc_chance: 100 };
+pub static ENRAGE: Ability = Ability { name: "Enrage", trigger: AbilityTrigger { t: AbilityTriggerType::AnyDamage,
+  source: ITSELF, target: OPPONENT, conditions: &[TriggerCondition::HealthBelow(50)] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::TriggerTarget, kind: EffectKind::Damage(PHYSICAL, 2) }],
+  required_traits: &[], forbidden_traits: &[], probability: 1.0, min_level: 5,
+  cooldown: 0, charges: Some(3), proc_chance: 100 };
+pub static AMBUSH: Ability = Ability { name: "Ambush", trigger: AbilityTrigger { t: AbilityTriggerType::AnyDamage,
+  source: ITSELF, target: OPPONENT, conditions: &[TriggerCondition::FirstHit] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::TriggerTarget, kind: EffectKind::Damage(PHYSICAL, 1) }],
+  required_traits: &[], forbidden_traits: &[], probability: 0.5, min_level: 3,
+  cooldown: 0, charges: None, proc_chance: 100 };
+pub static GORGE: Ability = Ability { name: "Gorge", trigger: AbilityTrigger { t: AbilityTriggerType::Damage(VAMPIRIC),
+  source: ITSELF, target: OPPONENT, conditions: &[TriggerCondition::DamageAbove(3)] },
+  effects: &[
+    AbilityEffect { target: AbilityEffectTarget::TriggerTarget, kind: EffectKind::Damage(VAMPIRIC, 1) },
+    AbilityEffect { target: AbilityEffectTarget::This, kind: EffectKind::Heal(1) }
+  ],
+  required_traits: &[], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 0, charges: None, proc_chance: 100 };
+pub static BURST: Ability = Ability { name: "Burst", trigger: AbilityTrigger { t: AbilityTriggerType::OnFaint,
+  source: ALL, target: ITSELF, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::TriggerTarget, kind: EffectKind::Damage(ACID, 2) }],
+  required_traits: &[attributes::ACIDIC], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 0, charges: None, proc_chance: 100 };
+pub static REFORM: Ability = Ability { name: "Reform", trigger: AbilityTrigger { t: AbilityTriggerType::OnFaint,
+  source: ALL, target: ITSELF, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::This, kind: EffectKind::Revive(25) }],
+  required_traits: &[], forbidden_traits: &[], probability: 0.5, min_level: 5,
+  cooldown: 0, charges: Some(1), proc_chance: 100 };
+pub static VENGEANCE: Ability = Ability { name: "Vengeance", trigger: AbilityTrigger { t: AbilityTriggerType::OnAllyFaint,
+  source: OPPONENT, target: ALLY, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::TriggerTarget, kind: EffectKind::Damage(PHYSICAL, 1) }],
+  required_traits: &[], forbidden_traits: &[], probability: 0.5, min_level: 3,
+  cooldown: 0, charges: None, proc_chance: 100 };
+pub static SPIT: Ability = Ability { name: "Spit", trigger: AbilityTrigger { t: AbilityTriggerType::TurnStart,
+  source: ITSELF, target: ITSELF, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::AnyOpponent, kind: EffectKind::Stacks(POISON, 1) }],
+  required_traits: &[attributes::POISONOUS], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 0, charges: None, proc_chance: 50 };
+pub static SIPHON: Ability = Ability { name: "Siphon", trigger: AbilityTrigger { t: AbilityTriggerType::TurnEnd,
+  source: ITSELF, target: ITSELF, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::AnyOpponent, kind: EffectKind::Damage(VAMPIRIC, 1) }],
+  required_traits: &[], forbidden_traits: &[], probability: 1.0, min_level: 3,
+  cooldown: 1, charges: None, proc_chance: 100 };
+pub static ACID_SPLASH: Ability = Ability { name: "Acid Splash", trigger: AbilityTrigger { t: AbilityTriggerType::EnterScene,
+  source: ITSELF, target: ITSELF, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::AllOpponent, kind: EffectKind::Damage(ACID, 1) }],
+  required_traits: &[attributes::ACIDIC], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 0, charges: None, proc_chance: 100 };
+pub static SPLIT: Ability = Ability { name: "Split", trigger: AbilityTrigger { t: AbilityTriggerType::AnyDamage,
+  source: OPPONENT, target: ITSELF, conditions: &[TriggerCondition::HealthBelow(50)] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::This,
+    kind: EffectKind::Summon(Summon { template: "Slime", count: 2, max: 2 }) }],
+  required_traits: &[], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 0, charges: Some(1), proc_chance: 100 };
+pub static REGENERATE: Ability = Ability { name: "Regenerate", trigger: AbilityTrigger { t: AbilityTriggerType::TurnStart,
+  source: ITSELF, target: ITSELF, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::This, kind: EffectKind::Heal(1) }],
+  required_traits: &[], forbidden_traits: &[], probability: 1.0, min_level: 3,
+  cooldown: 0, charges: None, proc_chance: 100 };
+pub static HARDEN: Ability = Ability { name: "Harden", trigger: AbilityTrigger { t: AbilityTriggerType::AnyDamage,
+  source: OPPONENT, target: ITSELF, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::This, kind: EffectKind::Modify(Modifier::Stat(Stat::Defense, 2), 2) }],
+  required_traits: &[], forbidden_traits: &[], probability: 0.5, min_level: 3,
+  cooldown: 2, charges: None, proc_chance: 100 };
+pub static CORRODE: Ability = Ability { name: "Corrode", trigger: AbilityTrigger { t: AbilityTriggerType::Damage(PHYSICAL),
+  source: ITSELF, target: OPPONENT, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::TriggerTarget,
+    kind: EffectKind::Modify(Modifier::Resistance(PHYSICAL, WEAKNESS), 2) }],
+  required_traits: &[attributes::ACIDIC], forbidden_traits: &[], probability: 1.0, min_level: 1,
+  cooldown: 0, charges: None, proc_chance: 30 };
+pub static SCREECH: Ability = Ability { name: "Screech", trigger: AbilityTrigger { t: AbilityTriggerType::EnterScene,
+  source: ITSELF, target: ITSELF, conditions: &[] },
+  effects: &[AbilityEffect { target: AbilityEffectTarget::AllOpponent, kind: EffectKind::Modify(Modifier::Stat(Stat::Attack, -1), 2) }],
+  required_traits: &[], forbidden_traits: &[], probability: 0.5, min_level: 1,
+  cooldown: 0, charges: None, proc_chance: 100 };
+
+pub static GELATINOUS_WALL: Aura = Aura { name: "Gelatinous Wall", target: ALLY,
+  effect: AuraEffect::DamageReduction(PHYSICAL, 1), min_level: 1 };
+pub static THINNED_BLOOD: Aura = Aura { name: "Thinned Blood", target: OPPONENT,
+  effect: AuraEffect::Resistance(POISON, NEUTRAL), min_level: 5 };
+
+pub static HEAD_BUMP: Attack = Attack { name: "Head Bump", cost: 1, damage: 3, t: PHYSICAL, required_traits: &[],
+  forbidden_traits: &[], prob: 1.0, min_level: 1, hit_chance: 100, crit_chance: 0, status: None,
+  shape: AttackShape::Single, summon: None };
+pub static PARASITE_BITE: Attack = Attack { name: "Parasite Bite", cost: 1, damage: 2, t: VAMPIRIC, required_traits: &[],
+  forbidden_traits: &[], prob: 1.0, min_level: 1, hit_chance: 100, crit_chance: 0, status: None,
+  shape: AttackShape::Single, summon: None };
+pub static POISON_STING: Attack = Attack { name: "Poison Sting", cost: 1, damage: 2, t: POISON, required_traits: &[attributes::POISONOUS],
+  forbidden_traits: &[], prob: 1.0, min_level: 1, hit_chance: 100, crit_chance: 10, status: None,
+  shape: AttackShape::Single, summon: None };
+pub static ENGULF: Attack = Attack { name: "Engulf", cost: 2, damage: 5, t: PHYSICAL, required_traits: &[],
+  forbidden_traits: &[], prob: 1.0, min_level: 5, hit_chance: 75, crit_chance: 0,
+  status: Some(StatusEffect { status: Status::Stun, turns: 1, chance: 50 }), shape: AttackShape::Single, summon: None };
+pub static BLOOD_DRAIN: Attack = Attack { name: "Blood Drain", cost: 2, damage: 4, t: VAMPIRIC, required_traits: &[],
+  forbidden_traits: &[], prob: 1.0, min_level: 1, hit_chance: 100, crit_chance: 0, status: None,
+  shape: AttackShape::Single, summon: None };
+pub static SONIC_SCREAM: Attack = Attack { name: "Sonic Scream", cost: 1, damage: 2, t: PHYSICAL, required_traits: &[],
+  forbidden_traits: &[], prob: 1.0, min_level: 1, hit_chance: 100, crit_chance: 0, status: None,
+  shape: AttackShape::Single, summon: None };
+pub static BODY_SLAM: Attack = Attack { name: "Body Slam", cost: 2, damage: 4, t: PHYSICAL, required_traits: &[],
+  forbidden_traits: &[], prob: 1.0, min_level: 1, hit_chance: 85, crit_chance: 10, status: None,
+  shape: AttackShape::Splash, summon: None };
+pub static FLURRY: Attack = Attack { name: "Flurry", cost: 1, damage: 1, t: PHYSICAL, required_traits: &[],
+  forbidden_traits: &[], prob: 1.0, min_level: 3, hit_chance: 90, crit_chance: 15, status: None,
+  shape: AttackShape::Random(3), summon: None };
+pub static CALL_SWARM: Attack = Attack { name: "Call Swarm", cost: 2, damage: 1, t: PHYSICAL, required_traits: &[],
+  forbidden_traits: &[], prob: 1.0, min_level: 5, hit_chance: 100, crit_chance: 0, status: None,
+  shape: AttackShape::Single, summon: Some(Summon { template: "Bat", count: 2, max: 2 }) };
+
+pub fn slime() -> EntityBuilder {
+  EntityBuilder::new(
+    "Slime",
+    "A small mass made out of a viscous substance.\n\
+    It would almost be cute... if it wasn't able to eat you whole.",
+    (2, 10), (&[], vec![
+      (PHYSICAL, RESISTANCE),
+      (VAMPIRIC, RESISTANCE)
+    ]),
+    vec![
+      (&[attributes::POISONOUS], vec![
+        (POISON, IMMUNITY)
+      ], 0.01),
+      (&[attributes::ACIDIC], vec![
+        (ACID, IMMUNITY)
+      ], 0.01)],
+    vec![&POISON_TOUCH, &ACID_TOUCH, &BURST, &ACID_SPLASH, &HARDEN, &CORRODE],
+    vec![&HEAD_BUMP, &ENGULF]
+  ).with_growth(Growth { health_per_level: 2, damage_per_level: 0.1 })
+  .with_evolution(Evolution { into: "Giant Slime", conditions: vec![EvolutionCondition::Level(5)] })
+}
+
+pub fn giant_slime() -> EntityBuilder {
+  EntityBuilder::new(
+    "Giant Slime",
+    "A slime that has eaten one adventurer too many.\n\
+    The ground shakes a little every time it bounces.",
+    (8, 20), (&[], vec![
+      (PHYSICAL, RESISTANCE),
+      (VAMPIRIC, RESISTANCE)
+    ]),
+    vec![
+      (&[attributes::POISONOUS], vec![
+        (POISON, IMMUNITY)
+      ], 0.01),
+      (&[attributes::ACIDIC], vec![
+        (ACID, IMMUNITY)
+      ], 0.01)],
+    vec![&POISON_TOUCH, &ACID_TOUCH, &ENRAGE, &BURST, &REFORM, &ACID_SPLASH, &SPLIT, &REGENERATE, &CORRODE],
+    vec![&HEAD_BUMP, &ENGULF, &BODY_SLAM]
+  ).with_growth(Growth { health_per_level: 3, damage_per_level: 0.1 })
+  .with_stats((0, 1), (1, 3))
+  .with_aura(&GELATINOUS_WALL)
+}
+
+pub fn leech() -> EntityBuilder {
+  EntityBuilder::new(
+    "Leech",
+    "A small parasite that quite literally sucks the life force out of you!",
+    (1, 5), (&[attributes::PHYSICAL], vec![
+      (VAMPIRIC, IMMUNITY)
+    ]),
+    vec![(&[attributes::POISONOUS], Vec::new(), 0.1)],
+    vec![&SPIT],
+    vec![&PARASITE_BITE, &POISON_STING]
+  ).with_growth(Growth { health_per_level: 1, damage_per_level: 0.15 })
+  .with_evolution(Evolution { into: "Bloated Leech", conditions: vec![EvolutionCondition::Level(3)] })
+}
+
+pub fn bloated_leech() -> EntityBuilder {
+  EntityBuilder::new(
+    "Bloated Leech",
+    "A leech so full of stolen blood it can barely move, which has never stopped it from wanting more.",
+    (4, 10), (&[attributes::PHYSICAL], vec![
+      (VAMPIRIC, IMMUNITY)
+    ]),
+    vec![(&[attributes::POISONOUS], Vec::new(), 0.1)],
+    vec![&GORGE, &SPIT, &SIPHON],
+    vec![&PARASITE_BITE, &POISON_STING, &BLOOD_DRAIN]
+  ).with_growth(Growth { health_per_level: 2, damage_per_level: 0.15 })
+  .with_stats((1, 3), (0, 1))
+  .with_aura(&THINNED_BLOOD)
+}
+
+pub fn bat() -> EntityBuilder {
+  EntityBuilder::new(
+    "Bat",
+    "One of the most iconic nocturnal creatures of Phunuse.",
+    (1, 4), (&[attributes::PHYSICAL], vec![
+      (ACID, RESISTANCE)
+    ]),
+    Vec::new(),
+    vec![&ECHO_STRIKE, &AMBUSH, &VENGEANCE, &SCREECH],
+    vec![&SONIC_SCREAM, &FLURRY, &CALL_SWARM]
+  ).with_growth(Growth { health_per_level: 1, damage_per_level: 0.1 })
+  .with_evasion(15)
+  .with_stats((0, 2), (0, 0))
+}
+
+/// Every creature template known to the bot
+pub fn all() -> Vec<EntityBuilder> {
+  vec![slime(), giant_slime(), leech(), bloated_leech(), bat()]
+}
+
+pub fn get(name: &str) -> Option<EntityBuilder> {
+  all().into_iter().find(|builder| builder.name.eq_ignore_ascii_case(name))
+}
+
+pub fn find_attack(name: &str) -> Option<&'static Attack> {
+  all().into_iter().flat_map(|builder| builder.attacks).find(|attack| attack.name == name)
+}
+
+pub fn find_ability(name: &str) -> Option<&'static Ability> {
+  all().into_iter().flat_map(|builder| builder.abilities).find(|ability| ability.name == name)
+}
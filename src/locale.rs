@@ -0,0 +1,150 @@
+use std::{collections::HashMap, fmt::Display, fs};
+use serde::{Serialize, Deserialize};
+
+mod en;
+mod fr;
+
+pub const DEFAULT_PATH: &str = "locales.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Locale {
+  #[default]
+  English,
+  French
+}
+
+pub const LOCALES: [Locale; 2] = [Locale::English, Locale::French];
+
+impl Locale {
+  /// Match a Discord locale code such as "en-US" or "fr"
+  pub fn from_code(code: &str) -> Option<Locale> {
+    let language = code.split('-').next()?;
+    LOCALES.into_iter().find(|locale| locale.code().eq_ignore_ascii_case(language))
+  }
+
+  pub fn code(&self) -> &'static str {
+    match self {
+      Locale::English => "en",
+      Locale::French => "fr"
+    }
+  }
+
+  fn catalog(&self) -> &'static [(&'static str, &'static str)] {
+    match self {
+      Locale::English => en::MESSAGES,
+      Locale::French => fr::MESSAGES
+    }
+  }
+
+  /// Message for `key`, falling back to English and then to the key itself
+  pub fn get(&self, key: &'static str) -> &'static str {
+    let find = |catalog: &'static [(&'static str, &'static str)]| {
+      catalog.iter().find(|(k, _)| *k == key).map(|(_, message)| *message)
+    };
+
+    find(self.catalog()).or_else(|| find(en::MESSAGES)).unwrap_or(key)
+  }
+
+}
+
+/// Key of the message agreeing with `count`, `one` for a single thing and `other` for any other amount
+pub fn plural(count: u8, one: &'static str, other: &'static str) -> &'static str {
+  if count == 1 { one } else { other }
+}
+
+impl Display for Locale {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Locale::English => write!(f, "English"),
+      Locale::French => write!(f, "Français")
+    }
+  }
+}
+
+/// Replace every `{0}`, `{1}`... of a catalog message with the matching argument, in a single pass
+/// so that arguments containing placeholders are left as they are
+pub fn fill(template: &str, args: &[&dyn Display]) -> String {
+  let mut result = String::with_capacity(template.len());
+  let mut rest = template;
+  while let Some(start) = rest.find('{') {
+    result += &rest[..start];
+    rest = &rest[start..];
+    let arg = rest[1..].find('}')
+      .and_then(|end| rest[1..=end].parse::<usize>().ok().map(|index| (index, end + 2)))
+      .and_then(|(index, len)| args.get(index).map(|arg| (arg, len)));
+    match arg {
+      Some((arg, len)) => {
+        result += &arg.to_string();
+        rest = &rest[len..];
+      },
+      None => {
+        result.push('{');
+        rest = &rest[1..];
+      }
+    }
+  }
+  result + rest
+}
+
+/// Look up a catalog message and fill in its arguments
+#[macro_export]
+macro_rules! tr {
+  ($locale:expr, $key:expr $(, $arg:expr)* $(,)?) => {
+    $crate::locale::fill($locale.get($key), &[$(&$arg as &dyn std::fmt::Display),*])
+  };
+}
+
+/// Renders a value in a given language, the plain `Display` impls render it in English
+pub struct Localized<'a, T>(pub &'a T, pub Locale);
+
+/// Languages picked by users and guilds, an explicit user choice wins over the guild's,
+/// which wins over the language of the user's Discord client
+#[derive(Default, Serialize, Deserialize)]
+pub struct LocaleSettings {
+  #[serde(skip)]
+  pub path: String,
+  pub users: HashMap<u64, Locale>,
+  pub guilds: HashMap<u64, Locale>
+}
+
+impl LocaleSettings {
+  pub fn load(path: &str) -> Self {
+    let mut settings: LocaleSettings = match fs::read_to_string(path) {
+      Ok(data) => serde_json::from_str(&data).expect("Could not parse locale settings."),
+      Err(_) => LocaleSettings::default()
+    };
+    settings.path = path.to_string();
+    settings
+  }
+
+  pub fn save(&self) -> std::io::Result<()> {
+    fs::write(&self.path, serde_json::to_string_pretty(self)?)
+  }
+
+  pub fn resolve(&self, user: u64, guild: Option<u64>, interaction: Option<&str>) -> Locale {
+    self.users.get(&user).copied()
+      .or_else(|| guild.and_then(|guild| self.guilds.get(&guild).copied()))
+      .or_else(|| interaction.and_then(Locale::from_code))
+      .unwrap_or_default()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn fills_every_placeholder() {
+    assert_eq!(fill("{1} hits {0}, {1}!", &[&"Slime", &"Bat"]), "Bat hits Slime, Bat!");
+  }
+
+  #[test]
+  fn arguments_are_not_filled_again() {
+    assert_eq!(fill("{0} and {1}", &[&"{1}", &"x"]), "{1} and x");
+  }
+
+  #[test]
+  fn unknown_placeholders_are_kept() {
+    assert_eq!(fill("{2} {a} {", &[&1]), "{2} {a} {");
+  }
+}
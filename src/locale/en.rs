@@ -0,0 +1,310 @@
+//! English messages, every other catalog falls back to these
+
+pub static MESSAGES: &[(&str, &str)] = &[
+  // Damage types
+  ("damage.physical", "physical"),
+  ("damage.poison", "poison"),
+  ("damage.acid", "acid"),
+  ("damage.vampiric", "vampiric"),
+  ("damage.fire", "fire"),
+
+  // Damage, healing and stacks
+  ("damage.attacked", "**{0}** is being attacked for {1} {2} damage.\n"),
+  ("damage.absorbed", "**{0}**'s shield absorbed {1} damage.\n"),
+  ("damage.aura_reduction", "Auras reduced the damage **{0}** took by {1}.\n"),
+  ("damage.took", "**{0}** took {1} {2} damage.\n"),
+  ("damage.weakness", "**{0}** is weak to {1} damage!\n"),
+  ("damage.resistance", "**{0}** is resistant to {1} damage!\n"),
+  ("damage.immunity", "**{0}** is immune to {1} damage!\n"),
+  ("damage.stacks", "**{0}** got {1} {2} stacks.\n"),
+  ("heal.healing", "**{0}** is being healed for {1} ❤️ \n"),
+  ("heal.healed", "**{0}** got healed by {1} ❤️ \n"),
+  ("heal.command", "Healed **{0}**#{1} for {2} ❤️ "),
+  ("heal.fainted", "Cannot heal **{0}**#{1} because it has already fainted!"),
+  ("heal.revived", "**{0}** got back up with {1} ❤️ !\n"),
+  ("ability.triggered", "`{0}` has been triggered! (#{1})\n"),
+  ("ability.proc", "🎲 {1} ≤ {2}%, `{0}` goes off!\n"),
+  ("ability.no_proc", "🎲 {1} > {2}%, `{0}` did not go off.\n"),
+  ("ability.cooldown.one", "⏳ ready in {0} turn"),
+  ("ability.cooldown.other", "⏳ ready in {0} turns"),
+  ("ability.charges", "🔋 {0}/{1} charges left"),
+
+  // Ability descriptions
+  ("trigger.heal_itself", "Upon healing itself"),
+  ("trigger.on_faint", "Upon fainting"),
+  ("trigger.on_ally_faint", "Whenever an ally faints"),
+  ("trigger.on_kill", "Upon knocking out {0}"),
+  ("trigger.turn_start", "At the start of its turn"),
+  ("trigger.turn_end", "At the end of its turn"),
+  ("trigger.enter_scene", "Upon entering the scene"),
+  ("trigger.source.itself", "Upon "),
+  ("trigger.source.ally", "Whenever an ally "),
+  ("trigger.source.opponent", "Whenever an opponent "),
+  ("trigger.source.team", "Whenever any creature on this team "),
+  ("trigger.source.all_but_ally", "Whenever itself or an opponent "),
+  ("trigger.source.all_but_self", "Whenever an ally or an opponent "),
+  ("trigger.source.all", "Whenever any creature "),
+  ("trigger.dealing_damage", "dealing damage to "),
+  ("trigger.dealing_typed_damage", "dealing {0} damage to "),
+  ("trigger.healing", "healing "),
+  ("trigger.deals_damage", "deals damage to "),
+  ("trigger.deals_typed_damage", "deals {0} damage to "),
+  ("trigger.heals", "heals "),
+  ("trigger.target.itself", "itself"),
+  ("trigger.target.this", "this creature"),
+  ("trigger.target.ally", "an ally"),
+  ("trigger.target.opponent", "an opponent"),
+  ("trigger.target.team", "any creature in this team"),
+  ("trigger.target.all_but_ally", "this creature or an ally"),
+  ("trigger.target.all_but_self", "an ally or an opponent"),
+  ("trigger.target.all", "any creature"),
+  ("condition.health_below", "while below {0}% health"),
+  ("condition.kills", "if that knocks it out"),
+  ("condition.first_hit", "for the first time this turn"),
+  ("condition.damage_above", "for more than {0} damage"),
+  ("effect.deal.one", "deal {0} {1} damage to {2}"),
+  ("effect.deal.other", "deal {0} {1} damage to {2}"),
+  ("effect.stacks.one", "add {0} {1} stack to {2}"),
+  ("effect.stacks.other", "add {0} {1} stacks to {2}"),
+  ("effect.heal", "heal {0} for {1} ❤️"),
+  ("effect.revive", "revive {0} with {1}% of its ❤️"),
+  ("effect.modify_stat.one", "give {0} {1} {2} for {3} turn"),
+  ("effect.modify_stat.other", "give {0} {1} {2} for {3} turns"),
+  ("effect.modify_resistance.one", "make {0} {1} to {2} for {3} turn"),
+  ("effect.modify_resistance.other", "make {0} {1} to {2} for {3} turns"),
+  ("effect.and", "{0} and {1}"),
+  ("effect.target.itself", "itself"),
+  ("effect.target.trigger_target", "that targeted creature"),
+  ("effect.target.any_ally", "a random ally"),
+  ("effect.target.any_opponent", "a random opponent"),
+  ("effect.target.all_allies", "all allies"),
+  ("effect.target.all_opponents", "all opponents"),
+
+  // Traits
+  ("trait.physical", "physical"),
+  ("trait.physical.description", "Has a body solid enough to hit things with."),
+  ("trait.poisonous", "poisonous"),
+  ("trait.poisonous.description", "Its body is laced with venom."),
+  ("trait.acidic", "acidic"),
+  ("trait.acidic.description", "Eats through whatever it touches."),
+  ("trait.vampiric", "vampiric"),
+  ("trait.vampiric.description", "Feeds on the life of others."),
+
+  // Statuses
+  ("status.stun", "stun"),
+  ("status.sleep", "sleep"),
+  ("status.burn", "burn"),
+  ("status.confuse", "confusion"),
+  ("status.inflicted.one", "**{0}** is affected by {1} for {2} turn.\n"),
+  ("status.inflicted.other", "**{0}** is affected by {1} for {2} turns.\n"),
+  ("status.expired", "**{0}** is no longer affected by {1}.\n"),
+  ("status.woke_up", "**{0}** woke up!\n"),
+  ("status.roll", "🎲 {0} ≤ {1}%\n"),
+  ("status.cannot_act", "**{0}**#{1} cannot act because of {2}!"),
+  ("status.confused", "🎲 {0} ≤ {1}%, **{2}**#{3} is confused and turns on its own team!\n"),
+  ("effect.status.one", "inflict {0} on {1} for {2} turn ({3}%)"),
+  ("effect.status.other", "inflict {0} on {1} for {2} turns ({3}%)"),
+  ("stat.attack", "attack"),
+  ("stat.defense", "defense"),
+  ("stat.evasion", "evasion"),
+  ("modifier.added.one", "**{0}** gets {1} for {2} turn.\n"),
+  ("modifier.added.other", "**{0}** gets {1} for {2} turns.\n"),
+
+  // Entities
+  ("entity.level", "Lv.{0}"),
+  ("experience.gained", "**{0}** gained {1} experience.\n"),
+  ("experience.level_up", "**{0}** reached level {1}!\n"),
+  ("experience.learned_attack", "**{0}** learned the `{1}` attack!\n"),
+  ("experience.learned_ability", "**{0}** learned the `{1}` ability!\n"),
+  ("experience.learned_aura", "**{0}** learned the `{1}` aura!\n"),
+  ("aura.target.itself", "itself"),
+  ("aura.target.allies", "allies"),
+  ("aura.target.opponents", "opponents"),
+  ("aura.target.team", "itself and its allies"),
+  ("aura.target.all", "every creature"),
+  ("aura.damage_reduction", "{0} less {1} damage taken by {2}"),
+  ("aura.resistance", "{0} damage resistance of {2} set to {1}"),
+  ("resistance.weakness", "weak"),
+  ("resistance.neutral", "neutral"),
+  ("resistance.resistance", "resistant"),
+  ("resistance.immunity", "immune"),
+  ("evolution.into", "Evolves into **{0}** {1}"),
+  ("evolution.and", " and "),
+  ("evolution.level", "at level {0}"),
+  ("evolution.item", "using `{0}`"),
+  ("evolution.traits", "with the {0} traits"),
+  ("slot.head", "head"),
+  ("slot.body", "body"),
+  ("slot.charm", "charm"),
+  ("equipment.weakness", "weakness to {0}"),
+  ("equipment.neutral", "neutral to {0}"),
+  ("equipment.resistance", "resistance to {0}"),
+  ("equipment.immunity", "immunity to {0}"),
+
+  // Items
+  ("item.effect.heal", "heal {0} ❤️"),
+  ("item.effect.cure", "cure {0} stacks"),
+  ("item.effect.shield", "shield {0} 🛡️"),
+  ("item.effect.revive", "revive with {0}% ❤️"),
+  ("item.cured", "**{0}** got rid of {1} {2} stacks.\n"),
+  ("item.shielded", "**{0}** is now shielded for {1} 🛡️\n"),
+  ("item.used", "Team \"{0}\" used `{1}` on **{2}**#{3}\n"),
+  ("item.missing", "Team \"{0}\" does not have any `{1}`"),
+  ("item.fainted", "Cannot use `{0}` on **{1}**#{2} because it has already fainted!"),
+  ("item.nothing_to_revive", "Cannot use `{0}` on **{1}**#{2}, it has not fainted!"),
+  ("item.wrong_team", "`{0}` cannot be used on **{1}**#{2}, it is not on team \"{3}\""),
+  ("item.no_energy", "Not enough 🔵 to use `{0}` ({1} needed)"),
+
+  // Cards
+  ("hand.title", "Team \"{0}\"'s hand\n{1}"),
+  ("hand.energy", "{0}/{1} 🔵  ({2} in deck, {3} discarded)\n"),
+  ("hand.inventory", "Inventory\n"),
+  ("hand.not_dealt", "Cards have not been dealt yet!"),
+  ("hand.time_left", "⏱️ {0}s left\n"),
+  ("card.missing", "There is no card #{0} in team \"{1}\"'s hand"),
+  ("card.wrong_team", "**{0}**#{1} is not on team \"{2}\""),
+  ("card.fainted", "**{0}**#{1} cannot play `{2}` because it has already fainted!"),
+  ("card.no_energy", "Not enough 🔵 to play `{0}` ({1} needed)"),
+  ("card.needs_target", "`{0}` needs a target"),
+  ("card.target_fainted", "**{0}**#{1} has already fainted!"),
+  ("card.known_ability", "**{0}**#{1} already knows `{2}`"),
+  ("card.learned_ability", "**{0}**#{1} learned the `{2}` ability!"),
+  ("card.cannot_learn", "**{0}**#{1} is not the kind of creature that can learn `{2}`"),
+
+  // Scene
+  ("attack.using", "**{0}**#{1} is using the `{2}` attack on **{3}**#{4}\n"),
+  ("attack.using_shape", "**{0}**#{1} is using the `{2}` attack, aiming at {3}\n"),
+  ("attack.no_targets", "**{0}**#{1} used `{2}` but there was nobody to hit!"),
+  ("attack.hit", "🎲 {0} ≤ {1}%, it hits!\n"),
+  ("attack.missed", "🎲 {3} > {4}%, `{2}` missed **{0}**#{1}!\n"),
+  ("attack.dodged", "🎲 {3} > {4}%, **{0}**#{1} dodged `{2}`!\n"),
+  ("attack.critical_roll", "🎲 {0} ≤ {1}%, critical hit! 💥\n"),
+  ("attack.critical", "Critical hit! 💥\n"),
+  ("scene.seeded", "Battle rolls are now seeded with `{0}`."),
+  ("attack.unconscious_attacker", "**{0}**#{1} tried using `{2}` but was unconscious!"),
+  ("attack.unconscious_target", "**{0}**#{1} was targetted by the `{2}` attack but is already unconscious!"),
+  ("shape.all_opponents", "all opponents"),
+  ("shape.all_allies", "all allies"),
+  ("shape.random.one", "{0} random opponent"),
+  ("shape.random.other", "{0} random opponents"),
+  ("shape.splash", "splashes neighbours for {0}%"),
+  ("summon.description", "summon {0} `{1}`, at most {2} at once"),
+  ("summon.summoned", "**{0}**#{1} summoned **{2}**#{3} on team \"{4}\"!\n"),
+  ("summon.limit", "**{0}**#{1} cannot have more than {3} `{2}` at once!\n"),
+  ("summon.team_full", "Team \"{0}\" is full, nothing else can join it!\n"),
+  ("summon.already_in_scene", "**{0}** is already in the scene!"),
+  ("summon.joined", "**{0}**#{1} joined team \"{2}\"!\n"),
+  ("scene.no_attacker", "Could not find attacking entity with id #{0}"),
+  ("scene.cards_dealt", "Cards have been dealt, attacks are played from your hand with /play now"),
+  ("scene.no_team", "There is no team \"{0}\""),
+  ("scene.filled", "Scene filled up!\n"),
+  ("scene.not_empty", "Cannot fill scene because it is not empty!"),
+  ("scene.nuked", "Nuke activated.\nCongrats, everything is gone now."),
+  ("scene.no_target", "Could not find target entity with id #{0}"),
+  ("scene.no_entity", "Could not find entity with id #{0}"),
+  ("scene.unknown_attack", "**{0}**#{1} does not know the `{2}` attack"),
+  ("scene.fainted", "**{0}** has fainted!\n"),
+  ("scene.victory", "Team \"{0}\" won the battle!\n"),
+  ("scene.draw", "Every team has fainted, the battle is a draw!\n"),
+  ("scene.battle_over", "The battle is over, deal the cards again with /deal to start a new one"),
+  ("scene.evolved", "**{0}**#{1} evolved into **{2}**!"),
+  ("scene.cannot_evolve", "**{0}**#{1} cannot evolve yet"),
+  ("scene.team", "Team \"{0}\"\n"),
+  ("scene.auras", "Active auras\n"),
+  ("scene.aura_owner", "**{0}**#{1}\n"),
+  ("scene.nothing", "Nothing in the scene yet!"),
+  ("scene.empty", "Cannot deal cards to an empty scene!"),
+  ("scene.no_teams", "There are no teams in the scene!"),
+  ("scene.dealt", "Dealt decks to {0} teams, playing by the {2} rules!\n{1}"),
+  ("scene.turn", "It is now team \"{0}\"'s turn!\n"),
+
+  // Collections
+  ("rarity.common", "Common"),
+  ("rarity.uncommon", "Uncommon"),
+  ("rarity.rare", "Rare"),
+  ("rarity.legendary", "Legendary"),
+  ("collection.empty", "Your collection is empty!"),
+  ("collection.creatures", "**Creatures** ({0})\n"),
+  ("collection.creature", "  [{0}] {1} **{2}** {3}  {4} ❤️\n"),
+  ("collection.items", "**Items** ({0})\n"),
+  ("collection.cards", "**Cards** ({0})\n"),
+  ("collection.not_owned", "You do not own a creature #{0}"),
+  ("collection.evolved", "**{0}** evolved into **{1}**!"),
+  ("collection.cannot_evolve", "Your creature #{0} cannot evolve yet"),
+  ("collection.no_spare_item", "You do not have a spare `{0}`"),
+  ("collection.unknown_item", "`{0}` does not exist anymore"),
+  ("collection.cannot_equip", "Creature #{0} cannot be equipped"),
+  ("collection.cannot_unequip", "Creature #{0} cannot be unequipped"),
+  ("collection.equipped", "**{0}** equipped `{1}`!"),
+  ("collection.unequipped", "**{0}** unequipped `{1}`"),
+  ("collection.nothing_equipped", "**{0}** has nothing equipped on its {1}"),
+  ("collection.no_slot", "There is no {0} slot"),
+  ("pack.summary", "**{0}** {1}{2}{3}\n  {4}"),
+  ("pack.card", " + 1 card"),
+  ("pack.item", " + 1 item"),
+  ("pack.missing", "There is no `{0}` pack"),
+  ("pack.opened", "Opened a **{0}** pack!\n"),
+  ("pack.creature", "{0} **{1}**  {2} ❤️\n"),
+
+  // Trades
+  ("trade.status.pending", "Pending"),
+  ("trade.status.accepted", "Accepted"),
+  ("trade.status.declined", "Declined"),
+  ("trade.status.countered", "Countered"),
+  ("trade.status.expired", "Expired"),
+  ("trade.status.failed", "Failed"),
+  ("trade.offer", "<@{0}> offers\n{1}in exchange for <@{2}>'s\n{3}({4})"),
+  ("trade.nothing", "  nothing\n"),
+  ("trade.no_longer_owned", "  *a creature that is no longer owned*\n"),
+  ("trade.history", "{0} with <@{1}>: gave {2}, received {3} <t:{4}:R>\n"),
+  ("trade.no_history", "No trades yet!"),
+  ("trade.not_owned", "<@{0}> does not own a creature #{1}"),
+  ("trade.offered_gone", "<@{0}> does not own every offered creature anymore"),
+  ("trade.requested_gone", "<@{0}> does not own every requested creature anymore"),
+  ("trade.accept", "Accept"),
+  ("trade.decline", "Decline"),
+  ("trade.unavailable", "This trade is no longer available."),
+  ("trade.expired", "This trade has expired."),
+  ("trade.completed", "Trade between <@{0}> and <@{1}> completed!"),
+  ("trade.failed", "Trade failed: {0}"),
+  ("trade.declined", "<@{0}> declined the trade."),
+  ("trade.cannot_answer", "You cannot answer this trade."),
+  ("trade.invalid_user", "You cannot trade with this user!"),
+  ("trade.empty", "A trade needs at least one creature on either side!"),
+  ("trade.none_proposed", "Nobody proposed you a trade!"),
+
+  // Encounters
+  ("difficulty.easy", "easy"),
+  ("difficulty.normal", "normal"),
+  ("difficulty.hard", "hard"),
+  ("difficulty.deadly", "deadly"),
+  ("encounter.appeared", "Creatures from the {0} show up on team \"{2}\"! ({1}, threat {3}/{4})\n"),
+  ("encounter.joined", "**{0}**#{1} joins the fight!\n"),
+  ("encounter.unknown_biome", "There is no `{0}` biome, pick one of\n"),
+  ("encounter.unknown_difficulty", "There is no `{0}` difficulty, pick Easy, Normal, Hard or Deadly"),
+  ("encounter.empty", "Nothing showed up in the {0}..."),
+  ("challenge.unknown_creature", "There is no creature named `{0}`"),
+  ("challenge.rating", "CR {0}\n  ❤️ {1} durability, ⚔️ {2} offense, ✨ {3} from abilities\n"),
+
+  // Rulesets
+  ("ruleset.teams", "up to {0} teams of {1}"),
+  ("ruleset.friendly_fire", "friendly fire"),
+  ("ruleset.no_friendly_fire", "no friendly fire"),
+  ("ruleset.no_self_targeting", "no targeting yourself"),
+  ("ruleset.resistances", "weaknesses ×{0}, resistances ×{1}, immunities ×{2}"),
+  ("ruleset.critical", "critical hits ×{0}"),
+  ("ruleset.vampiric", "vampiric healing {0}%"),
+  ("ruleset.time_limit", "{0}s per turn"),
+  ("ruleset.friendly_fire_denied", "**{0}**#{1} cannot attack its ally **{2}**#{3} under the {4} rules!"),
+  ("ruleset.self_targeting_denied", "**{0}**#{1} cannot target itself under the {2} rules!"),
+  ("ruleset.no_more_teams", "The {0} rules allow at most {1} teams!"),
+  ("ruleset.too_many_teams", "The {0} rules allow at most {1} teams, there are {2} in the scene!"),
+  ("ruleset.team_too_big", "The {0} rules allow at most {1} creatures per team, team \"{2}\" has {3}!"),
+  ("ruleset.missing", "There are no `{0}` rules"),
+  ("ruleset.time_up", "⏱️ Team \"{0}\" ran out of time!\n"),
+
+  // Settings
+  ("language.unknown", "Unknown language `{0}`, pick one of {1} or `auto`"),
+  ("language.user", "Battles will now be narrated in {0} for you."),
+  ("language.server", "Battles will now be narrated in {0} on this server.")
+];
@@ -0,0 +1,310 @@
+//! French messages
+
+pub static MESSAGES: &[(&str, &str)] = &[
+  // Damage types
+  ("damage.physical", "physique"),
+  ("damage.poison", "poison"),
+  ("damage.acid", "acide"),
+  ("damage.vampiric", "vampirique"),
+  ("damage.fire", "feu"),
+
+  // Damage, healing and stacks
+  ("damage.attacked", "**{0}** subit une attaque de {1} dégâts de type {2}.\n"),
+  ("damage.absorbed", "Le bouclier de **{0}** a absorbé {1} dégâts.\n"),
+  ("damage.aura_reduction", "Des auras ont réduit de {1} les dégâts subis par **{0}**.\n"),
+  ("damage.took", "**{0}** a subi {1} dégâts de type {2}.\n"),
+  ("damage.weakness", "**{0}** est vulnérable aux dégâts de type {1} !\n"),
+  ("damage.resistance", "**{0}** résiste aux dégâts de type {1} !\n"),
+  ("damage.immunity", "**{0}** est immunisé contre les dégâts de type {1} !\n"),
+  ("damage.stacks", "**{0}** a reçu {1} charges de type {2}.\n"),
+  ("heal.healing", "**{0}** reçoit {1} ❤️ de soins\n"),
+  ("heal.healed", "**{0}** a récupéré {1} ❤️ \n"),
+  ("heal.command", "**{0}**#{1} a été soigné de {2} ❤️ "),
+  ("heal.fainted", "Impossible de soigner **{0}**#{1} : il est déjà K.O. !"),
+  ("heal.revived", "**{0}** se relève avec {1} ❤️ !\n"),
+  ("ability.triggered", "`{0}` a été déclenché ! (#{1})\n"),
+  ("ability.proc", "🎲 {1} ≤ {2} %, `{0}` se déclenche !\n"),
+  ("ability.no_proc", "🎲 {1} > {2} %, `{0}` ne s'est pas déclenché.\n"),
+  ("ability.cooldown.one", "⏳ prêt dans {0} tour"),
+  ("ability.cooldown.other", "⏳ prêt dans {0} tours"),
+  ("ability.charges", "🔋 {0}/{1} charges restantes"),
+
+  // Ability descriptions
+  ("trigger.heal_itself", "En se soignant"),
+  ("trigger.on_faint", "En tombant K.O."),
+  ("trigger.on_ally_faint", "Chaque fois qu'un allié tombe K.O."),
+  ("trigger.on_kill", "En mettant K.O. {0}"),
+  ("trigger.turn_start", "Au début de son tour"),
+  ("trigger.turn_end", "À la fin de son tour"),
+  ("trigger.enter_scene", "En entrant en scène"),
+  ("trigger.source.itself", "En "),
+  ("trigger.source.ally", "Chaque fois qu'un allié "),
+  ("trigger.source.opponent", "Chaque fois qu'un adversaire "),
+  ("trigger.source.team", "Chaque fois qu'une créature de cette équipe "),
+  ("trigger.source.all_but_ally", "Chaque fois que cette créature ou un adversaire "),
+  ("trigger.source.all_but_self", "Chaque fois qu'un allié ou un adversaire "),
+  ("trigger.source.all", "Chaque fois qu'une créature "),
+  ("trigger.dealing_damage", "infligeant des dégâts à "),
+  ("trigger.dealing_typed_damage", "infligeant des dégâts de type {0} à "),
+  ("trigger.healing", "soignant "),
+  ("trigger.deals_damage", "inflige des dégâts à "),
+  ("trigger.deals_typed_damage", "inflige des dégâts de type {0} à "),
+  ("trigger.heals", "soigne "),
+  ("trigger.target.itself", "elle-même"),
+  ("trigger.target.this", "cette créature"),
+  ("trigger.target.ally", "un allié"),
+  ("trigger.target.opponent", "un adversaire"),
+  ("trigger.target.team", "une créature de cette équipe"),
+  ("trigger.target.all_but_ally", "cette créature ou un allié"),
+  ("trigger.target.all_but_self", "un allié ou un adversaire"),
+  ("trigger.target.all", "n'importe quelle créature"),
+  ("condition.health_below", "en ayant moins de {0} % de sa vie"),
+  ("condition.kills", "si cela le met K.O."),
+  ("condition.first_hit", "pour la première fois ce tour-ci"),
+  ("condition.damage_above", "pour plus de {0} dégâts"),
+  ("effect.deal.one", "infliger {0} dégât de type {1} à {2}"),
+  ("effect.deal.other", "infliger {0} dégâts de type {1} à {2}"),
+  ("effect.stacks.one", "ajouter {0} charge de type {1} à {2}"),
+  ("effect.stacks.other", "ajouter {0} charges de type {1} à {2}"),
+  ("effect.heal", "soigner {0} de {1} ❤️"),
+  ("effect.revive", "ranimer {0} avec {1}% de ses ❤️"),
+  ("effect.modify_stat.one", "donner {1} {2} à {0} pendant {3} tour"),
+  ("effect.modify_stat.other", "donner {1} {2} à {0} pendant {3} tours"),
+  ("effect.modify_resistance.one", "rendre {0} {1} au type {2} pendant {3} tour"),
+  ("effect.modify_resistance.other", "rendre {0} {1} au type {2} pendant {3} tours"),
+  ("effect.and", "{0} et {1}"),
+  ("effect.target.itself", "elle-même"),
+  ("effect.target.trigger_target", "la créature ciblée"),
+  ("effect.target.any_ally", "un allié au hasard"),
+  ("effect.target.any_opponent", "un adversaire au hasard"),
+  ("effect.target.all_allies", "tous les alliés"),
+  ("effect.target.all_opponents", "tous les adversaires"),
+
+  // Traits
+  ("trait.physical", "physique"),
+  ("trait.physical.description", "A un corps assez solide pour frapper."),
+  ("trait.poisonous", "venimeux"),
+  ("trait.poisonous.description", "Son corps est imprégné de venin."),
+  ("trait.acidic", "acide"),
+  ("trait.acidic.description", "Ronge tout ce qu'il touche."),
+  ("trait.vampiric", "vampirique"),
+  ("trait.vampiric.description", "Se nourrit de la vie des autres."),
+
+  // Statuses
+  ("status.stun", "étourdissement"),
+  ("status.sleep", "sommeil"),
+  ("status.burn", "brûlure"),
+  ("status.confuse", "confusion"),
+  ("status.inflicted.one", "**{0}** : {1} pendant {2} tour.\n"),
+  ("status.inflicted.other", "**{0}** : {1} pendant {2} tours.\n"),
+  ("status.expired", "**{0}** se remet de : {1}.\n"),
+  ("status.woke_up", "**{0}** s'est réveillé !\n"),
+  ("status.roll", "🎲 {0} ≤ {1} %\n"),
+  ("status.cannot_act", "**{0}**#{1} ne peut pas agir ({2}) !"),
+  ("status.confused", "🎲 {0} ≤ {1} %, **{2}**#{3} est confus et s'en prend à sa propre équipe !\n"),
+  ("effect.status.one", "infliger {0} à {1} pendant {2} tour ({3} %)"),
+  ("effect.status.other", "infliger {0} à {1} pendant {2} tours ({3} %)"),
+  ("stat.attack", "attaque"),
+  ("stat.defense", "défense"),
+  ("stat.evasion", "esquive"),
+  ("modifier.added.one", "**{0}** obtient {1} pendant {2} tour.\n"),
+  ("modifier.added.other", "**{0}** obtient {1} pendant {2} tours.\n"),
+
+  // Entities
+  ("entity.level", "Niv.{0}"),
+  ("experience.gained", "**{0}** a gagné {1} points d'expérience.\n"),
+  ("experience.level_up", "**{0}** a atteint le niveau {1} !\n"),
+  ("experience.learned_attack", "**{0}** a appris l'attaque `{1}` !\n"),
+  ("experience.learned_ability", "**{0}** a appris la capacité `{1}` !\n"),
+  ("experience.learned_aura", "**{0}** a appris l'aura `{1}` !\n"),
+  ("aura.target.itself", "elle-même"),
+  ("aura.target.allies", "les alliés"),
+  ("aura.target.opponents", "les adversaires"),
+  ("aura.target.team", "elle-même et ses alliés"),
+  ("aura.target.all", "toutes les créatures"),
+  ("aura.damage_reduction", "{0} de dégâts de type {1} en moins pour {2}"),
+  ("aura.resistance", "résistance aux dégâts de type {0} fixée à « {1} » pour {2}"),
+  ("resistance.weakness", "faible"),
+  ("resistance.neutral", "neutre"),
+  ("resistance.resistance", "résistant"),
+  ("resistance.immunity", "immunisé"),
+  ("evolution.into", "Évolue en **{0}** {1}"),
+  ("evolution.and", " et "),
+  ("evolution.level", "au niveau {0}"),
+  ("evolution.item", "avec `{0}`"),
+  ("evolution.traits", "avec les traits {0}"),
+  ("slot.head", "tête"),
+  ("slot.body", "corps"),
+  ("slot.charm", "talisman"),
+  ("equipment.weakness", "faiblesse à {0}"),
+  ("equipment.neutral", "neutre à {0}"),
+  ("equipment.resistance", "résistance à {0}"),
+  ("equipment.immunity", "immunité à {0}"),
+
+  // Items
+  ("item.effect.heal", "soigne {0} ❤️"),
+  ("item.effect.cure", "retire les charges de type {0}"),
+  ("item.effect.shield", "bouclier de {0} 🛡️"),
+  ("item.effect.revive", "ranime avec {0}% de ❤️"),
+  ("item.cured", "**{0}** s'est débarrassé de {1} charges de type {2}.\n"),
+  ("item.shielded", "**{0}** est maintenant protégé par {1} 🛡️\n"),
+  ("item.used", "L'équipe « {0} » a utilisé `{1}` sur **{2}**#{3}\n"),
+  ("item.missing", "L'équipe « {0} » n'a pas de `{1}`"),
+  ("item.fainted", "Impossible d'utiliser `{0}` sur **{1}**#{2} : il est déjà K.O. !"),
+  ("item.nothing_to_revive", "Impossible d'utiliser `{0}` sur **{1}**#{2} : il n'est pas K.O. !"),
+  ("item.wrong_team", "Impossible d'utiliser `{0}` sur **{1}**#{2} : il ne fait pas partie de l'équipe « {3} »"),
+  ("item.no_energy", "Pas assez de 🔵 pour utiliser `{0}` ({1} requis)"),
+
+  // Cards
+  ("hand.title", "Main de l'équipe « {0} »\n{1}"),
+  ("hand.energy", "{0}/{1} 🔵  ({2} dans la pioche, {3} défaussées)\n"),
+  ("hand.inventory", "Inventaire\n"),
+  ("hand.not_dealt", "Les cartes n'ont pas encore été distribuées !"),
+  ("hand.time_left", "⏱️ {0} s restantes\n"),
+  ("card.missing", "Il n'y a pas de carte #{0} dans la main de l'équipe « {1} »"),
+  ("card.wrong_team", "**{0}**#{1} ne fait pas partie de l'équipe « {2} »"),
+  ("card.fainted", "**{0}**#{1} ne peut pas jouer `{2}` : il est déjà K.O. !"),
+  ("card.no_energy", "Pas assez de 🔵 pour jouer `{0}` ({1} requis)"),
+  ("card.needs_target", "`{0}` nécessite une cible"),
+  ("card.target_fainted", "**{0}**#{1} est déjà K.O. !"),
+  ("card.known_ability", "**{0}**#{1} connaît déjà `{2}`"),
+  ("card.learned_ability", "**{0}**#{1} a appris la capacité `{2}` !"),
+  ("card.cannot_learn", "**{0}**#{1} n'est pas le genre de créature à pouvoir apprendre `{2}`"),
+
+  // Scene
+  ("attack.using", "**{0}**#{1} utilise l'attaque `{2}` sur **{3}**#{4}\n"),
+  ("attack.using_shape", "**{0}**#{1} utilise l'attaque `{2}` et vise {3}\n"),
+  ("attack.no_targets", "**{0}**#{1} a utilisé `{2}` mais il n'y avait personne à toucher !"),
+  ("attack.hit", "🎲 {0} ≤ {1} %, l'attaque touche !\n"),
+  ("attack.missed", "🎲 {3} > {4} %, `{2}` a raté **{0}**#{1} !\n"),
+  ("attack.dodged", "🎲 {3} > {4} %, **{0}**#{1} a esquivé `{2}` !\n"),
+  ("attack.critical_roll", "🎲 {0} ≤ {1} %, coup critique ! 💥\n"),
+  ("attack.critical", "Coup critique ! 💥\n"),
+  ("scene.seeded", "Les jets des combats utilisent maintenant la graine `{0}`."),
+  ("attack.unconscious_attacker", "**{0}**#{1} a tenté d'utiliser `{2}` mais est inconscient !"),
+  ("attack.unconscious_target", "**{0}**#{1} a été ciblé par l'attaque `{2}` mais est déjà inconscient !"),
+  ("shape.all_opponents", "tous les adversaires"),
+  ("shape.all_allies", "tous les alliés"),
+  ("shape.random.one", "{0} adversaire au hasard"),
+  ("shape.random.other", "{0} adversaires au hasard"),
+  ("shape.splash", "éclabousse les voisins à {0} %"),
+  ("summon.description", "invoquer {0} `{1}`, {2} au plus à la fois"),
+  ("summon.summoned", "**{0}**#{1} a invoqué **{2}**#{3} dans l'équipe \"{4}\" !\n"),
+  ("summon.limit", "**{0}**#{1} ne peut pas avoir plus de {3} `{2}` à la fois !\n"),
+  ("summon.team_full", "L'équipe \"{0}\" est pleine, plus personne ne peut la rejoindre !\n"),
+  ("summon.already_in_scene", "**{0}** est déjà dans la scène !"),
+  ("summon.joined", "**{0}**#{1} a rejoint l'équipe « {2} » !\n"),
+  ("scene.no_attacker", "Aucun attaquant avec l'identifiant #{0}"),
+  ("scene.cards_dealt", "Les cartes ont été distribuées, les attaques se jouent maintenant depuis votre main avec /play"),
+  ("scene.no_team", "Il n'y a pas d'équipe « {0} »"),
+  ("scene.filled", "La scène est remplie !\n"),
+  ("scene.not_empty", "Impossible de remplir la scène : elle n'est pas vide !"),
+  ("scene.nuked", "Bombe activée.\nBravo, il n'y a plus rien."),
+  ("scene.no_target", "Aucune cible avec l'identifiant #{0}"),
+  ("scene.no_entity", "Aucune créature avec l'identifiant #{0}"),
+  ("scene.unknown_attack", "**{0}**#{1} ne connaît pas l'attaque `{2}`"),
+  ("scene.fainted", "**{0}** est K.O. !\n"),
+  ("scene.victory", "L'équipe « {0} » a remporté le combat !\n"),
+  ("scene.draw", "Toutes les équipes sont K.O., le combat se termine par une égalité !\n"),
+  ("scene.battle_over", "Le combat est terminé, redistribuez les cartes avec /deal pour en commencer un nouveau"),
+  ("scene.evolved", "**{0}**#{1} a évolué en **{2}** !"),
+  ("scene.cannot_evolve", "**{0}**#{1} ne peut pas encore évoluer"),
+  ("scene.team", "Équipe « {0} »\n"),
+  ("scene.auras", "Auras actives\n"),
+  ("scene.aura_owner", "**{0}**#{1}\n"),
+  ("scene.nothing", "La scène est encore vide !"),
+  ("scene.empty", "Impossible de distribuer des cartes dans une scène vide !"),
+  ("scene.no_teams", "Il n'y a aucune équipe dans la scène !"),
+  ("scene.dealt", "Des decks ont été distribués à {0} équipes, avec les règles {2} !\n{1}"),
+  ("scene.turn", "C'est au tour de l'équipe « {0} » !\n"),
+
+  // Collections
+  ("rarity.common", "Commun"),
+  ("rarity.uncommon", "Peu commun"),
+  ("rarity.rare", "Rare"),
+  ("rarity.legendary", "Légendaire"),
+  ("collection.empty", "Votre collection est vide !"),
+  ("collection.creatures", "**Créatures** ({0})\n"),
+  ("collection.creature", "  [{0}] {1} **{2}** {3}  {4} ❤️\n"),
+  ("collection.items", "**Objets** ({0})\n"),
+  ("collection.cards", "**Cartes** ({0})\n"),
+  ("collection.not_owned", "Vous ne possédez pas de créature #{0}"),
+  ("collection.evolved", "**{0}** a évolué en **{1}** !"),
+  ("collection.cannot_evolve", "Votre créature #{0} ne peut pas encore évoluer"),
+  ("collection.no_spare_item", "Vous n'avez pas de `{0}` en réserve"),
+  ("collection.unknown_item", "`{0}` n'existe plus"),
+  ("collection.cannot_equip", "La créature #{0} ne peut pas être équipée"),
+  ("collection.cannot_unequip", "La créature #{0} ne peut pas être déséquipée"),
+  ("collection.equipped", "**{0}** s'est équipé de `{1}` !"),
+  ("collection.unequipped", "**{0}** a retiré `{1}`"),
+  ("collection.nothing_equipped", "**{0}** n'a rien d'équipé à l'emplacement {1}"),
+  ("collection.no_slot", "Il n'y a pas d'emplacement {0}"),
+  ("pack.summary", "**{0}** {1}{2}{3}\n  {4}"),
+  ("pack.card", " + 1 carte"),
+  ("pack.item", " + 1 objet"),
+  ("pack.missing", "Il n'y a pas de paquet `{0}`"),
+  ("pack.opened", "Paquet **{0}** ouvert !\n"),
+  ("pack.creature", "{0} **{1}**  {2} ❤️\n"),
+
+  // Trades
+  ("trade.status.pending", "En attente"),
+  ("trade.status.accepted", "Accepté"),
+  ("trade.status.declined", "Refusé"),
+  ("trade.status.countered", "Contre-proposé"),
+  ("trade.status.expired", "Expiré"),
+  ("trade.status.failed", "Échoué"),
+  ("trade.offer", "<@{0}> propose\n{1}en échange de ce que possède <@{2}> :\n{3}({4})"),
+  ("trade.nothing", "  rien\n"),
+  ("trade.no_longer_owned", "  *une créature qui n'est plus possédée*\n"),
+  ("trade.history", "{0} avec <@{1}> : {2} donnée(s), {3} reçue(s) <t:{4}:R>\n"),
+  ("trade.no_history", "Aucun échange pour l'instant !"),
+  ("trade.not_owned", "<@{0}> ne possède pas de créature #{1}"),
+  ("trade.offered_gone", "<@{0}> ne possède plus toutes les créatures proposées"),
+  ("trade.requested_gone", "<@{0}> ne possède plus toutes les créatures demandées"),
+  ("trade.accept", "Accepter"),
+  ("trade.decline", "Refuser"),
+  ("trade.unavailable", "Cet échange n'est plus disponible."),
+  ("trade.expired", "Cet échange a expiré."),
+  ("trade.completed", "Échange entre <@{0}> et <@{1}> effectué !"),
+  ("trade.failed", "L'échange a échoué : {0}"),
+  ("trade.declined", "<@{0}> a refusé l'échange."),
+  ("trade.cannot_answer", "Vous ne pouvez pas répondre à cet échange."),
+  ("trade.invalid_user", "Vous ne pouvez pas échanger avec cet utilisateur !"),
+  ("trade.empty", "Un échange demande au moins une créature d'un côté ou de l'autre !"),
+  ("trade.none_proposed", "Personne ne vous a proposé d'échange !"),
+
+  // Encounters
+  ("difficulty.easy", "facile"),
+  ("difficulty.normal", "normal"),
+  ("difficulty.hard", "difficile"),
+  ("difficulty.deadly", "mortel"),
+  ("encounter.appeared", "Des créatures venues de : {0} rejoignent l'équipe \"{2}\" ! ({1}, menace {3}/{4})\n"),
+  ("encounter.joined", "**{0}**#{1} se joint au combat !\n"),
+  ("encounter.unknown_biome", "Il n'y a pas de biome `{0}`, choisissez parmi\n"),
+  ("encounter.unknown_difficulty", "Il n'y a pas de difficulté `{0}`, choisissez Easy, Normal, Hard ou Deadly"),
+  ("encounter.empty", "Rien n'est apparu ({0})..."),
+  ("challenge.unknown_creature", "Il n'y a pas de créature nommée `{0}`"),
+  ("challenge.rating", "FP {0}\n  ❤️ {1} de résistance, ⚔️ {2} d'attaque, ✨ {3} de capacités\n"),
+
+  // Rulesets
+  ("ruleset.teams", "jusqu'à {0} équipes de {1}"),
+  ("ruleset.friendly_fire", "tirs alliés"),
+  ("ruleset.no_friendly_fire", "pas de tirs alliés"),
+  ("ruleset.no_self_targeting", "pas de ciblage de soi-même"),
+  ("ruleset.resistances", "faiblesses ×{0}, résistances ×{1}, immunités ×{2}"),
+  ("ruleset.critical", "coups critiques ×{0}"),
+  ("ruleset.vampiric", "soins vampiriques {0} %"),
+  ("ruleset.time_limit", "{0} s par tour"),
+  ("ruleset.friendly_fire_denied", "**{0}**#{1} ne peut pas attaquer son allié **{2}**#{3} avec les règles {4} !"),
+  ("ruleset.self_targeting_denied", "**{0}**#{1} ne peut pas se cibler avec les règles {2} !"),
+  ("ruleset.no_more_teams", "Les règles {0} autorisent au plus {1} équipes !"),
+  ("ruleset.too_many_teams", "Les règles {0} autorisent au plus {1} équipes, il y en a {2} dans la scène !"),
+  ("ruleset.team_too_big", "Les règles {0} autorisent au plus {1} créatures par équipe, l'équipe \"{2}\" en a {3} !"),
+  ("ruleset.missing", "Il n'y a pas de règles `{0}`"),
+  ("ruleset.time_up", "⏱️ L'équipe \"{0}\" n'a plus de temps !\n"),
+
+  // Settings
+  ("language.unknown", "Langue `{0}` inconnue, choisissez parmi {1} ou `auto`"),
+  ("language.user", "Langue de narration de vos combats : {0}."),
+  ("language.server", "Langue de narration des combats sur ce serveur : {0}.")
+];
@@ -1,20 +1,29 @@
 use std::sync::{Arc, Mutex};
 
-use dotenvy;
 use poise::serenity_prelude as serenity;
 
 mod scene;
-use entity::ability::{AbilityTriggerType, trigger_target::{ITSELF, ALLY, OPPONENT}, AbilityEffectTarget};
 use scene::Scene;
 mod entity;
-use entity::{Attack, Ability, EntityBuilder};
-use entity::dmg_type::{PHYSICAL, POISON, ACID, VAMPIRIC};
-use entity::attributes;
-use entity::{AbilityTrigger, AbilityEffect};
-use entity::dmg_resistance::DamageResistance::{RESISTANCE, IMMUNITY};
+use entity::templates;
+use entity::equipment::Slot;
+mod collection;
+use collection::CollectionStore;
+use collection::trade::{Trade, TradeBook, TradeStatus, TRADE_EXPIRY};
+mod pack;
+mod encounter;
+mod locale;
+use locale::{Locale, LocaleSettings, Localized};
 
 type Error = Box<dyn std::error::Error + Send + Sync>;
-type Context<'a> = poise::Context<'a, Arc<Mutex<Scene>>, Error>;
+type Context<'a> = poise::Context<'a, Data, Error>;
+
+pub struct Data {
+  pub scene: Arc<Mutex<Scene>>,
+  pub collections: Arc<Mutex<CollectionStore>>,
+  pub trades: Arc<Mutex<TradeBook>>,
+  pub locales: Arc<Mutex<LocaleSettings>>
+}
 
 #[tokio::main]
 async fn main() { 
@@ -27,7 +36,22 @@ async fn main() {
         heal(),
         describe_entity(),
         nuke(),
-        fill_scene()
+        fill_scene(),
+        encounter(),
+        challenge(),
+        evolve(),
+        deal(),
+        rulesets(),
+        hand(),
+        play(),
+        end_turn(),
+        seed(),
+        use_item(),
+        collection(),
+        packs(),
+        open_pack(),
+        trade(),
+        language()
       ],
       ..Default::default()
     })
@@ -37,7 +61,14 @@ async fn main() {
       Box::pin(async move {
         poise::builtins::register_globally(ctx, &framework.options().commands).await?;
 
-        Ok(Arc::new(Mutex::from(Scene::new())))
+        let path = std::env::var("COLLECTION_PATH").unwrap_or(String::from(collection::DEFAULT_PATH));
+        let locale_path = std::env::var("LOCALE_PATH").unwrap_or(String::from(locale::DEFAULT_PATH));
+        Ok(Data {
+          scene: Arc::new(Mutex::from(Scene::new())),
+          collections: Arc::new(Mutex::from(CollectionStore::load(&path))),
+          trades: Arc::new(Mutex::from(TradeBook::default())),
+          locales: Arc::new(Mutex::from(LocaleSettings::load(&locale_path)))
+        })
       })
     });
 
@@ -49,9 +80,9 @@ async fn main() {
   default_member_permissions = "SEND_MESSAGES",
   required_bot_permissions = "SEND_MESSAGES")]
 async fn describe_scene(ctx: Context<'_>) -> Result<(), Error> {
-  let mut result = Arc::clone(ctx.data()).lock().unwrap().describe_scene();
-  if result.len() == 0 {
-    result = String::from("Nothing in the scene yet!");
+  let (mut result, locale) = with_scene(ctx, |scene| (scene.describe_scene(), scene.locale));
+  if result.is_empty() {
+    result = locale.get("scene.nothing").to_string();
   }
   ctx.say(result).await?;
   Ok(())
@@ -66,7 +97,8 @@ async fn attack(ctx: Context<'_>,
   #[description = "Attack name"] attack_name: String,
   #[description = "Attacker ID"] attacker: u8,
   #[description = "Attack target ID"] target: u8) -> Result<(), Error> {
-    let result = Arc::clone(ctx.data()).lock().unwrap().attack(&attack_name, attacker, target);
+    let result = with_scene(ctx, |scene| scene.attack(&attack_name, attacker, target));
+    sync_collections(ctx)?;
     ctx.say(result).await?;
     Ok(())
 }
@@ -79,16 +111,17 @@ async fn attack(ctx: Context<'_>,
 async fn heal(ctx: Context<'_>,
   #[description = "Target entity ID"] target: u8,
   #[description = "Heal amount"] amount: u8) -> Result<(), Error> {
-    let mut result = format!("Could not find entity with id #{}", target);
-    if let Some(e) = Arc::clone(ctx.data()).lock().unwrap().get_mut_entity_from_id(target) {
-      if e.is_alive() {
-        let healed_amt = e.heal(amount);
-        result = format!("Healed **{}**#{} for {} ❤️ ", e.name, target, healed_amt);
-      }
-      else {
-        result = format!("Cannot heal **{}**#{} because it has already fainted!", e.name, target);
+    let result = with_scene(ctx, |scene| {
+      let locale = scene.locale;
+      match scene.get_mut_entity_from_id(target) {
+        Some(e) if e.is_alive() => {
+          let healed_amt = e.heal(amount);
+          tr!(locale, "heal.command", e.name, target, healed_amt)
+        },
+        Some(e) => tr!(locale, "heal.fainted", e.name, target),
+        None => tr!(locale, "scene.no_entity", target)
       }
-    }
+    });
     ctx.say(result).await?;
     Ok(())
 }
@@ -99,10 +132,22 @@ async fn heal(ctx: Context<'_>,
   required_bot_permissions = "SEND_MESSAGES")]
 async fn describe_entity(ctx: Context<'_>,
   #[description = "Entity ID"] id: u8) -> Result<(), Error> {
-    let mut result = format!("Could not find entity with id #{}", id);
-    if let Some(e) = Arc::clone(ctx.data()).lock().unwrap().get_mut_entity_from_id(id) {
-      result = e.describe();
-    }
+    let result = with_scene(ctx, |scene| match scene.get_entity_from_id(id) {
+      Some(e) => e.describe(scene.locale),
+      None => tr!(scene.locale, "scene.no_entity", id)
+    });
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Evolve an entity in the scene
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn evolve(ctx: Context<'_>,
+  #[description = "Entity ID"] id: u8) -> Result<(), Error> {
+    let result = with_scene(ctx, |scene| scene.evolve(id, None));
     ctx.say(result).await?;
     Ok(())
 }
@@ -112,8 +157,8 @@ async fn describe_entity(ctx: Context<'_>,
   default_member_permissions = "SEND_MESSAGES",
   required_bot_permissions = "SEND_MESSAGES")]
 async fn nuke(ctx: Context<'_>) -> Result<(), Error> {
-  Arc::clone(ctx.data()).lock().unwrap().nuke();
-  ctx.say("Nuke activated.\nCongrats, everything is gone now.").await?;
+  Arc::clone(&ctx.data().scene).lock().unwrap().nuke();
+  ctx.say(resolve_locale(ctx).get("scene.nuked")).await?;
   Ok(())
 }
 
@@ -122,84 +167,726 @@ async fn nuke(ctx: Context<'_>) -> Result<(), Error> {
   default_member_permissions = "SEND_MESSAGES",
   required_bot_permissions = "SEND_MESSAGES")]
 async fn fill_scene(ctx: Context<'_>) -> Result<(), Error> {
-  if Arc::clone(ctx.data()).lock().unwrap().is_empty() {
-    let slime_builder = EntityBuilder::new(
-      "Slime",
-      "A small mass made out of a viscous substance.\n\
-      It would almost be cute... if it wasn't able to eat you whole.",
-      (2, 10), (attributes::NONE, vec![
-        (PHYSICAL, RESISTANCE),
-        (VAMPIRIC, RESISTANCE)
-      ]),
-      vec![
-        (attributes::POISONOUS, vec![
-          (POISON, IMMUNITY)
-        ], 0.01),
-        (attributes::ACIDIC, vec![
-          (ACID, IMMUNITY)
-        ], 0.01)],
-      vec![
-        &Ability { name: "Poison Touch", trigger: AbilityTrigger { t: AbilityTriggerType::Damage(PHYSICAL),
-          source: ITSELF, target: OPPONENT },
-          effect: AbilityEffect { target: AbilityEffectTarget::TriggerTarget, damage: 1, t: POISON },
-          required_traits: attributes::POISONOUS, forbidden_traits: attributes::NONE, probability: 1.0 },
-        &Ability { name: "Acid Touch", trigger: AbilityTrigger { t: AbilityTriggerType::Damage(PHYSICAL), source: ITSELF, target: OPPONENT},
-          effect: AbilityEffect { target: AbilityEffectTarget::TriggerTarget, damage: 1, t: ACID },
-          required_traits: attributes::ACIDIC, forbidden_traits: attributes::NONE, probability: 1.0 }
-      ],
-      vec![
-        &Attack { name: "Head Bump", cost: 1, damage: 3, t: PHYSICAL, required_traits: attributes::NONE,
-          forbidden_traits: attributes::NONE, prob: 1.0 }
-      ]
-    );
-
-    let leech_builder = EntityBuilder::new(
-      "Leech",
-      "A small parasite that quite literally sucks the life force out of you!",
-      (1, 5), (attributes::PHYSICAL, vec![
-        (VAMPIRIC, IMMUNITY)
-      ]),
-      vec![(attributes::POISONOUS, Vec::new(), 0.1)],
-      Vec::new(),
-      vec![
-        &Attack { name: "Parasite Bite", cost: 1, damage: 2, t: VAMPIRIC, required_traits: attributes::NONE,
-        forbidden_traits: attributes::NONE, prob: 1.0 },
-        &Attack { name: "Poison Sting", cost: 1, damage: 2, t: POISON, required_traits: attributes::POISONOUS,
-        forbidden_traits: attributes::NONE, prob: 1.0 }
-      ]
-    );
-  
-    let bat_builder = EntityBuilder::new(
-      "Bat",
-      "One of the most iconic nocturnal creatures of Phunuse.",
-      (1, 4), (attributes::PHYSICAL, vec![
-        (ACID, RESISTANCE)
-      ]),
-      Vec::new(),
-      vec![
-        &Ability { name: "Echo Strike", trigger: AbilityTrigger { t: AbilityTriggerType::AnyDamage, source: ITSELF, target: ALLY | OPPONENT },
-          effect: AbilityEffect { target: AbilityEffectTarget::TriggerTarget, damage: 1, t: PHYSICAL },
-          required_traits: attributes::NONE, forbidden_traits: attributes::NONE, probability: 1.0 }
-      ],
-      vec![
-        &Attack { name: "Sonic Scream", cost: 1, damage: 2, t: PHYSICAL, required_traits: attributes::NONE,
-        forbidden_traits: attributes::NONE, prob: 1.0 }
-      ]
-    );
+  if Arc::clone(&ctx.data().scene).lock().unwrap().is_empty() {
+    let slime = templates::slime().build();
+    let bat = templates::bat().build();
+    let leech = templates::leech().build();
 
-    let slime = slime_builder.build();
-    let bat = bat_builder.build();
-    let leech = leech_builder.build();
+    let result = with_scene(ctx, |scene| {
+      let mut result = scene.locale.get("scene.filled").to_string();
+      for (team, entity) in [("A", &slime), ("A", &bat), ("B", &leech)] {
+        let id = scene.register(team, entity);
+        result += &scene.enter(id);
+      }
+      result
+    });
+    sync_collections(ctx)?;
 
-    Arc::clone(ctx.data()).lock().unwrap().register("A", &slime);
-    Arc::clone(ctx.data()).lock().unwrap().register("A", &bat);
-    Arc::clone(ctx.data()).lock().unwrap().register("B", &leech);
+    ctx.say(result).await?;
+  }
+  else {
+    ctx.say(resolve_locale(ctx).get("scene.not_empty")).await?;
+  }
+  Ok(())
+}
 
-    ctx.say("Scene filled up!").await?;
+/// Fill a team with wild creatures from a biome
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn encounter(ctx: Context<'_>,
+  #[description = "Biome the creatures come from"] biome: Option<String>,
+  #[description = "Easy, Normal, Hard or Deadly"] difficulty: Option<String>,
+  #[description = "Team name (A, B, C or D)"] team: Option<String>) -> Result<(), Error> {
+    let biome = biome.unwrap_or(String::from(encounter::SPAWN_TABLES[0].name));
+    let difficulty = difficulty.unwrap_or(String::from("Normal"));
+    let team = team.unwrap_or(String::from("B"));
+    let locale = resolve_locale(ctx);
+
+    let result = match (encounter::get(&biome), encounter::Difficulty::from_name(&difficulty),
+      scene::TEAM_NAMES.iter().find(|name| name.eq_ignore_ascii_case(&team))) {
+      (None, _, _) => {
+        let mut result = tr!(locale, "encounter.unknown_biome", biome);
+        for table in encounter::SPAWN_TABLES.iter() {
+          result += &table.to_string();
+        }
+        result
+      },
+      (_, None, _) => tr!(locale, "encounter.unknown_difficulty", difficulty),
+      (_, _, None) => tr!(locale, "scene.no_team", team),
+      (Some(table), Some(difficulty), Some(team)) => with_scene(ctx, |scene| scene.encounter(table, difficulty, team))
+    };
+    sync_collections(ctx)?;
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Estimate how strong creatures are without having to fight them
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn challenge(ctx: Context<'_>,
+  #[description = "Creature name, every creature if left out"] name: Option<String>) -> Result<(), Error> {
+    let locale = resolve_locale(ctx);
+    let builders = match name {
+      Some(name) => match templates::get(&name) {
+        Some(builder) => vec![builder],
+        None => {
+          let result = tr!(locale, "challenge.unknown_creature", name);
+          ctx.say(result).await?;
+          return Ok(());
+        }
+      },
+      None => templates::all()
+    };
+
+    let mut ratings: Vec<_> = builders.iter().map(|builder| (builder.name, builder.challenge_rating())).collect();
+    ratings.sort_by(|(_, a), (_, b)| b.rating().total_cmp(&a.rating()));
+    let mut result = String::new();
+    for (name, rating) in ratings {
+      result += &format!("**{}**  {}", name, Localized(&rating, locale));
+    }
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Deal a deck of cards to every team in the scene
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn deal(ctx: Context<'_>,
+  #[description = "Rules of the battle, see /rulesets"] ruleset: Option<String>) -> Result<(), Error> {
+    let ruleset = match ruleset {
+      Some(name) => match scene::ruleset::get(&name) {
+        Some(ruleset) => Some(ruleset),
+        None => {
+          let result = tr!(resolve_locale(ctx), "ruleset.missing", name);
+          ctx.say(result).await?;
+          return Ok(());
+        }
+      },
+      None => None
+    };
+
+    let result = with_scene(ctx, |scene| scene.deal(ruleset));
+    sync_collections(ctx)?;
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// List the rules battles can be played by
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn rulesets(ctx: Context<'_>) -> Result<(), Error> {
+  let locale = resolve_locale(ctx);
+  let mut result = String::new();
+  for ruleset in scene::ruleset::PRESETS.iter() {
+    result += &Localized(ruleset, locale).to_string();
+  }
+  ctx.say(result).await?;
+  Ok(())
+}
+
+/// Show the hand of the team whose turn it is
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn hand(ctx: Context<'_>) -> Result<(), Error> {
+  let result = with_scene(ctx, |scene| scene.describe_hand());
+  ctx.say(result).await?;
+  Ok(())
+}
+
+/// Play a card from the current hand
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn play(ctx: Context<'_>,
+  #[description = "Card number in hand"] card: usize,
+  #[description = "Entity ID playing the card"] user: u8,
+  #[description = "Attack target ID"] target: Option<u8>) -> Result<(), Error> {
+    let result = with_scene(ctx, |scene| scene.play_card(card, user, target));
+    sync_collections(ctx)?;
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Use an item from the current team's inventory
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn use_item(ctx: Context<'_>,
+  #[description = "Item name"] item: String,
+  #[description = "Target entity ID"] target: u8) -> Result<(), Error> {
+    let result = with_scene(ctx, |scene| scene.use_item(&item, target));
+    sync_collections(ctx)?;
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// End the current team's turn
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn end_turn(ctx: Context<'_>) -> Result<(), Error> {
+  let result = with_scene(ctx, |scene| scene.end_turn());
+  sync_collections(ctx)?;
+  ctx.say(result).await?;
+  Ok(())
+}
+
+/// Seed battle rolls so that the same actions always play out the same way
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn seed(ctx: Context<'_>,
+  #[description = "Seed for every following roll"] seed: u64) -> Result<(), Error> {
+    let result = with_scene(ctx, |scene| {
+      scene.seed(seed);
+      tr!(scene.locale, "scene.seeded", seed)
+    });
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Browse your creature and card collection
+#[poise::command(slash_command,
+  subcommands("list", "inspect", "summon", "evolve_owned", "equip", "unequip"),
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn collection(ctx: Context<'_>) -> Result<(), Error> {
+  list_inner(ctx).await
+}
+
+/// List every creature and card you own
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn list(ctx: Context<'_>) -> Result<(), Error> {
+  list_inner(ctx).await
+}
+
+async fn list_inner(ctx: Context<'_>) -> Result<(), Error> {
+  let result = match Arc::clone(&ctx.data().collections).lock().unwrap().get(ctx.author().id.0) {
+    Some(collection) => collection.describe(resolve_locale(ctx)),
+    None => resolve_locale(ctx).get("collection.empty").to_string()
+  };
+  ctx.say(result).await?;
+  Ok(())
+}
+
+/// Inspect a creature from your collection
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn inspect(ctx: Context<'_>,
+  #[description = "Creature number in your collection"] index: usize) -> Result<(), Error> {
+    let locale = resolve_locale(ctx);
+    let result = Arc::clone(&ctx.data().collections).lock().unwrap().get(ctx.author().id.0)
+      .and_then(|collection| collection.describe_creature(index, locale))
+      .unwrap_or(tr!(locale, "collection.not_owned", index));
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// List the booster packs that can be opened
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn packs(ctx: Context<'_>) -> Result<(), Error> {
+  let locale = resolve_locale(ctx);
+  let mut result = String::new();
+  for pack in pack::PACKS.iter() {
+    result += &Localized(pack, locale).to_string();
+  }
+  ctx.say(result).await?;
+  Ok(())
+}
+
+/// Open a booster pack and add its creatures to your collection
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn open_pack(ctx: Context<'_>,
+  #[description = "Pack name"] name: Option<String>) -> Result<(), Error> {
+    let locale = resolve_locale(ctx);
+    let name = name.unwrap_or(String::from(pack::PACKS[0].name));
+    let pack = match pack::get(&name) {
+      Some(pack) => pack,
+      None => {
+        let result = tr!(locale, "pack.missing", name);
+        ctx.say(result).await?;
+        return Ok(());
+      }
+    };
+
+    let mut result = tr!(locale, "pack.opened", pack.name);
+    {
+      let collections = Arc::clone(&ctx.data().collections);
+      let mut collections = collections.lock().unwrap();
+      let creatures = pack.open();
+      for (entity, rarity) in creatures.iter() {
+        result += &tr!(locale, "pack.creature", Localized(rarity, locale), entity.name, entity.max_health);
+        collections.add_creature(ctx.author().id.0, entity);
+      }
+      if let Some(card) = pack::Pack::roll_card(&creatures) {
+        result += &Localized(&card, locale).to_string();
+        collections.add_card(ctx.author().id.0, &card);
+      }
+      if let Some(item) = pack.roll_item() {
+        result += &Localized(item, locale).to_string();
+        collections.get_mut(ctx.author().id.0).items.push(item.name.to_string());
+      }
+      collections.save()?;
+    }
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Bring a creature from your collection into the scene
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn summon(ctx: Context<'_>,
+  #[description = "Creature number in your collection"] index: usize,
+  #[description = "Team name (A, B, C or D)"] team: String) -> Result<(), Error> {
+    let locale = resolve_locale(ctx);
+    let entity = Arc::clone(&ctx.data().collections).lock().unwrap().get(ctx.author().id.0)
+      .and_then(|collection| collection.creatures.get(index))
+      .and_then(|creature| creature.to_entity());
+
+    let result = match (entity, scene::TEAM_NAMES.iter().find(|name| name.eq_ignore_ascii_case(&team))) {
+      (None, _) => tr!(locale, "collection.not_owned", index),
+      (_, None) => tr!(locale, "scene.no_team", team),
+      (Some(entity), Some(team)) => with_scene(ctx, |scene| {
+        if scene.contains(entity.id) {
+          tr!(locale, "summon.already_in_scene", entity.name)
+        }
+        else if let Err(result) = scene.check_room(team) {
+          result
+        }
+        else {
+          let id = scene.register(team, &entity);
+          tr!(locale, "summon.joined", entity.name, id, team) + &scene.enter(id)
+        }
+      })
+    };
+    sync_collections(ctx)?;
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Evolve a creature from your collection
+#[poise::command(slash_command, rename = "evolve",
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn evolve_owned(ctx: Context<'_>,
+  #[description = "Creature number in your collection"] index: usize) -> Result<(), Error> {
+    let result = {
+      let collections = Arc::clone(&ctx.data().collections);
+      let mut collections = collections.lock().unwrap();
+      let locale = resolve_locale(ctx);
+      match collections.get_mut(ctx.author().id.0).evolve(index, None, locale) {
+        Some(result) => {
+          collections.save()?;
+          result
+        },
+        None => tr!(locale, "collection.cannot_evolve", index)
+      }
+    };
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Equip one of your items on a creature
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn equip(ctx: Context<'_>,
+  #[description = "Creature number in your collection"] index: usize,
+  #[description = "Item name"] item: String) -> Result<(), Error> {
+    let result = {
+      let collections = Arc::clone(&ctx.data().collections);
+      let mut collections = collections.lock().unwrap();
+      match collections.get_mut(ctx.author().id.0).equip(index, &item, resolve_locale(ctx)) {
+        Ok(result) => {
+          collections.save()?;
+          result
+        },
+        Err(reason) => reason
+      }
+    };
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Take an item off a creature
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn unequip(ctx: Context<'_>,
+  #[description = "Creature number in your collection"] index: usize,
+  #[description = "Slot (head, body or charm)"] slot: String) -> Result<(), Error> {
+    let locale = resolve_locale(ctx);
+    let result = match Slot::from_name(&slot) {
+      Some(slot) => {
+        let collections = Arc::clone(&ctx.data().collections);
+        let mut collections = collections.lock().unwrap();
+        match collections.get_mut(ctx.author().id.0).unequip(index, slot, locale) {
+          Ok(result) => {
+            collections.save()?;
+            result
+          },
+          Err(reason) => reason
+        }
+      },
+      None => tr!(locale, "collection.no_slot", slot)
+    };
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Language for whoever invoked the command, see `LocaleSettings::resolve`
+fn resolve_locale(ctx: Context<'_>) -> Locale {
+  let guild = ctx.guild_id().map(|guild| guild.0);
+  Arc::clone(&ctx.data().locales).lock().unwrap().resolve(ctx.author().id.0, guild, ctx.locale())
+}
+
+/// Run something on the scene with its narration in the invoking user's language
+fn with_scene<T>(ctx: Context<'_>, f: impl FnOnce(&mut Scene) -> T) -> T {
+  let locale = resolve_locale(ctx);
+  let scene = Arc::clone(&ctx.data().scene);
+  let mut scene = scene.lock().unwrap();
+  scene.locale = locale;
+  f(&mut scene)
+}
+
+/// Save the progress of owned creatures once a battle is over
+fn sync_collections(ctx: Context<'_>) -> Result<(), Error> {
+  let entities = Arc::clone(&ctx.data().scene).lock().unwrap().take_finished_entities();
+  if entities.is_empty() {
+    return Ok(());
+  }
+
+  let collections = Arc::clone(&ctx.data().collections);
+  let mut collections = collections.lock().unwrap();
+  let mut changed = false;
+  for entity in entities.iter() {
+    changed |= collections.sync_entity(entity);
+  }
+  if changed {
+    collections.save()?;
+  }
+  Ok(())
+}
+
+/// Trade creatures with other users
+#[poise::command(slash_command,
+  subcommands("propose", "counter", "history"),
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn trade(_ctx: Context<'_>) -> Result<(), Error> {
+  Ok(())
+}
+
+/// Turn a list of collection numbers such as "0, 2" into creature IDs
+fn parse_creatures(store: &CollectionStore, user: u64, list: &str, locale: Locale) -> Result<Vec<uuid::Uuid>, String> {
+  let mut result = Vec::new();
+  for index in list.split(',').map(|s| s.trim()).filter(|s| !s.is_empty()) {
+    let creature = index.parse::<usize>().ok()
+      .and_then(|index| store.get(user)?.creatures.get(index));
+    match creature {
+      Some(creature) => result.push(creature.id),
+      None => return Err(tr!(locale, "trade.not_owned", user, index))
+    }
+  }
+  Ok(result)
+}
+
+/// Post a trade with accept/decline buttons and resolve it once answered or expired
+async fn await_trade(ctx: Context<'_>, trade: Trade) -> Result<(), Error> {
+  let locale = resolve_locale(ctx);
+  let description = Arc::clone(&ctx.data().collections).lock().unwrap().describe_trade(&trade, locale);
+  let prefix = trade.id.to_string();
+
+  let reply = ctx.send(|m| {
+    m.content(description).components(|c| {
+      c.create_action_row(|r| {
+        r.create_button(|b| b.style(serenity::ButtonStyle::Success).label(locale.get("trade.accept"))
+            .custom_id(format!("{}:accept", prefix)))
+          .create_button(|b| b.style(serenity::ButtonStyle::Danger).label(locale.get("trade.decline"))
+            .custom_id(format!("{}:decline", prefix)))
+      })
+    })
+  }).await?;
+
+  while let Some(press) = serenity::CollectComponentInteraction::new(ctx)
+    .channel_id(ctx.channel_id())
+    .timeout(std::time::Duration::from_secs((trade.created_at + TRADE_EXPIRY).saturating_sub(collection::trade::now())))
+    .filter({
+      let prefix = prefix.clone();
+      move |press| press.data.custom_id.starts_with(&prefix)
+    })
+    .await
+  {
+    let user = press.user.id.0;
+    let accept = press.data.custom_id.ends_with(":accept");
+
+    let (response, done) = {
+      let trades = Arc::clone(&ctx.data().trades);
+      let mut trades = trades.lock().unwrap();
+      let collections = Arc::clone(&ctx.data().collections);
+      let mut collections = collections.lock().unwrap();
+
+      match trades.pending.get(&trade.id) {
+        None => (locale.get("trade.unavailable").to_string(), true),
+        Some(t) if t.is_expired() => {
+          let t = trades.close(trade.id, TradeStatus::Expired).unwrap();
+          collections.log_trade(&t);
+          collections.save()?;
+          (locale.get("trade.expired").to_string(), true)
+        },
+        Some(t) if accept && user == t.recipient => {
+          match collections.execute_trade(t, locale) {
+            Ok(()) => {
+              let t = trades.close(trade.id, TradeStatus::Accepted).unwrap();
+              collections.log_trade(&t);
+              collections.save()?;
+              (tr!(locale, "trade.completed", t.proposer, t.recipient), true)
+            },
+            Err(reason) => {
+              let t = trades.close(trade.id, TradeStatus::Failed).unwrap();
+              println!("Trade {} failed: {}", t.id, reason);
+              collections.log_trade(&t);
+              collections.save()?;
+              (tr!(locale, "trade.failed", reason), true)
+            }
+          }
+        },
+        Some(t) if !accept && (user == t.recipient || user == t.proposer) => {
+          let t = trades.close(trade.id, TradeStatus::Declined).unwrap();
+          collections.log_trade(&t);
+          collections.save()?;
+          (tr!(locale, "trade.declined", user), true)
+        },
+        Some(_) => (locale.get("trade.cannot_answer").to_string(), false)
+      }
+    };
+
+    if done {
+      press.create_interaction_response(ctx, |r| {
+        r.kind(serenity::InteractionResponseType::UpdateMessage)
+          .interaction_response_data(|d| d.content(response).components(|c| c))
+      }).await?;
+      return Ok(());
+    }
+
+    press.create_interaction_response(ctx, |r| {
+      r.kind(serenity::InteractionResponseType::ChannelMessageWithSource)
+        .interaction_response_data(|d| d.content(response).ephemeral(true))
+    }).await?;
+  }
+
+  // Nobody answered in time
+  let expired = {
+    let trades = Arc::clone(&ctx.data().trades);
+    let mut trades = trades.lock().unwrap();
+    let collections = Arc::clone(&ctx.data().collections);
+    let mut collections = collections.lock().unwrap();
+    match trades.close(trade.id, TradeStatus::Expired) {
+      Some(t) => {
+        collections.log_trade(&t);
+        collections.save()?;
+        true
+      },
+      None => false
+    }
+  };
+  if expired {
+    reply.edit(ctx, |m| m.content(locale.get("trade.expired")).components(|c| c)).await?;
   }
   else {
-    ctx.say("Cannot fill scene because it is not empty!").await?;
+    reply.edit(ctx, |m| m.components(|c| c)).await?;
   }
   Ok(())
 }
 
+/// Propose a trade to another user
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn propose(ctx: Context<'_>,
+  #[description = "User to trade with"] user: serenity::User,
+  #[description = "Your creature numbers, e.g. \"0, 2\""] offer: String,
+  #[description = "Their creature numbers, e.g. \"1\""] request: String) -> Result<(), Error> {
+    let locale = resolve_locale(ctx);
+    if user.id == ctx.author().id || user.bot {
+      ctx.say(locale.get("trade.invalid_user")).await?;
+      return Ok(());
+    }
+
+    let trade = {
+      let collections = Arc::clone(&ctx.data().collections);
+      let collections = collections.lock().unwrap();
+      parse_creatures(&collections, ctx.author().id.0, &offer, locale).and_then(|offered| {
+        Ok(Trade::new(ctx.author().id.0, user.id.0, offered, parse_creatures(&collections, user.id.0, &request, locale)?))
+      }).and_then(|trade| if trade.is_empty() { Err(locale.get("trade.empty").to_string()) } else { Ok(trade) })
+    };
+
+    match trade {
+      Ok(trade) => {
+        Arc::clone(&ctx.data().trades).lock().unwrap().propose(&trade);
+        await_trade(ctx, trade).await
+      },
+      Err(reason) => {
+        ctx.say(reason).await?;
+        Ok(())
+      }
+    }
+}
+
+/// Answer the latest trade proposed to you with another offer
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn counter(ctx: Context<'_>,
+  #[description = "Your creature numbers, e.g. \"0, 2\""] offer: String,
+  #[description = "Their creature numbers, e.g. \"1\""] request: String) -> Result<(), Error> {
+    let author = ctx.author().id.0;
+    let locale = resolve_locale(ctx);
+    let trade = {
+      let trades = Arc::clone(&ctx.data().trades);
+      let mut trades = trades.lock().unwrap();
+      let collections = Arc::clone(&ctx.data().collections);
+      let mut collections = collections.lock().unwrap();
+
+      match trades.latest_for(author).map(|t| (t.id, t.proposer)) {
+        Some((id, proposer)) => {
+          let trade = parse_creatures(&collections, author, &offer, locale).and_then(|offered| {
+            Ok(Trade::new(author, proposer, offered, parse_creatures(&collections, proposer, &request, locale)?))
+          }).and_then(|trade| if trade.is_empty() { Err(locale.get("trade.empty").to_string()) } else { Ok(trade) });
+          if let Ok(trade) = &trade {
+            let old = trades.close(id, TradeStatus::Countered).unwrap();
+            collections.log_trade(&old);
+            collections.save()?;
+            trades.propose(trade);
+          }
+          trade
+        },
+        None => Err(locale.get("trade.none_proposed").to_string())
+      }
+    };
+
+    match trade {
+      Ok(trade) => await_trade(ctx, trade).await,
+      Err(reason) => {
+        ctx.say(reason).await?;
+        Ok(())
+      }
+    }
+}
+
+/// Show your past trades
+#[poise::command(slash_command,
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn history(ctx: Context<'_>) -> Result<(), Error> {
+  let locale = resolve_locale(ctx);
+  let result = match Arc::clone(&ctx.data().collections).lock().unwrap().get(ctx.author().id.0) {
+    Some(collection) => collection.describe_trades(ctx.author().id.0, locale),
+    None => locale.get("trade.no_history").to_string()
+  };
+  ctx.say(result).await?;
+  Ok(())
+}
+
+/// Choose the language used to narrate battles
+#[poise::command(slash_command,
+  subcommands("language_user", "language_server"),
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn language(_ctx: Context<'_>) -> Result<(), Error> {
+  Ok(())
+}
+
+/// Choose your own language, "auto" follows the server and your Discord client
+#[poise::command(slash_command,
+  rename = "user",
+  default_member_permissions = "SEND_MESSAGES",
+  required_bot_permissions = "SEND_MESSAGES")]
+async fn language_user(ctx: Context<'_>,
+  #[description = "Language code such as \"en\" or \"fr\", or \"auto\""] code: String) -> Result<(), Error> {
+    let current = resolve_locale(ctx);
+    let result = {
+      let locales = Arc::clone(&ctx.data().locales);
+      let mut locales = locales.lock().unwrap();
+      let user = ctx.author().id.0;
+      match set_locale(&mut locales.users, user, &code, current) {
+        Ok(()) => {
+          locales.save()?;
+          let locale = locales.resolve(user, ctx.guild_id().map(|guild| guild.0), ctx.locale());
+          tr!(locale, "language.user", locale)
+        },
+        Err(result) => result
+      }
+    };
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Choose the language of the whole server, members can still pick their own
+#[poise::command(slash_command,
+  rename = "server",
+  default_member_permissions = "MANAGE_GUILD",
+  required_permissions = "MANAGE_GUILD",
+  required_bot_permissions = "SEND_MESSAGES",
+  guild_only)]
+async fn language_server(ctx: Context<'_>,
+  #[description = "Language code such as \"en\" or \"fr\", or \"auto\""] code: String) -> Result<(), Error> {
+    let current = resolve_locale(ctx);
+    let result = {
+      let locales = Arc::clone(&ctx.data().locales);
+      let mut locales = locales.lock().unwrap();
+      let guild = ctx.guild_id().unwrap().0;
+      match set_locale(&mut locales.guilds, guild, &code, current) {
+        Ok(()) => {
+          locales.save()?;
+          let locale = locales.guilds.get(&guild).copied()
+            .unwrap_or(ctx.locale().and_then(Locale::from_code).unwrap_or_default());
+          tr!(locale, "language.server", locale)
+        },
+        Err(result) => result
+      }
+    };
+    ctx.say(result).await?;
+    Ok(())
+}
+
+/// Store the locale matching `code` for `id`, "auto" removes it instead, errors are told in `current`
+fn set_locale(settings: &mut std::collections::HashMap<u64, Locale>, id: u64, code: &str, current: Locale)
+  -> Result<(), String> {
+  if code.eq_ignore_ascii_case("auto") {
+    settings.remove(&id);
+    return Ok(());
+  }
+
+  match Locale::from_code(code) {
+    Some(locale) => {
+      settings.insert(id, locale);
+      Ok(())
+    },
+    None => {
+      let codes: Vec<String> = locale::LOCALES.iter().map(|locale| format!("`{}` ({})", locale.code(), locale)).collect();
+      Err(tr!(current, "language.unknown", code, codes.join(", ")))
+    }
+  }
+}
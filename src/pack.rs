@@ -0,0 +1,150 @@
+use std::fmt::Display;
+use rand::seq::SliceRandom;
+
+use crate::entity::{Entity, EntityBuilder, templates, equipment::{self, Equipment}};
+use crate::scene::deck::Card;
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+/// Maximum amount of rolls a slot gets to reach its rarity before settling for the rarest roll
+pub const MAX_ROLLS: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Rarity {
+  Common,
+  Uncommon,
+  Rare,
+  Legendary
+}
+
+impl Rarity {
+  /// Rarity of a creature based on how unlikely its rolled variants were
+  pub fn from_probability(probability: f32) -> Self {
+    if probability > 0.25 { Rarity::Common }
+    else if probability > 0.05 { Rarity::Uncommon }
+    else if probability > 0.005 { Rarity::Rare }
+    else { Rarity::Legendary }
+  }
+
+  pub fn of(builder: &EntityBuilder, entity: &Entity) -> Self {
+    Rarity::from_probability(builder.variant_probability(&entity.attributes))
+  }
+
+  pub fn icon(&self) -> &'static str {
+    match self {
+      Rarity::Common => "⚪",
+      Rarity::Uncommon => "🟢",
+      Rarity::Rare => "🔷",
+      Rarity::Legendary => "🌟"
+    }
+  }
+
+  pub fn localized_name(&self, locale: Locale) -> &'static str {
+    match self {
+      Rarity::Common => locale.get("rarity.common"),
+      Rarity::Uncommon => locale.get("rarity.uncommon"),
+      Rarity::Rare => locale.get("rarity.rare"),
+      Rarity::Legendary => locale.get("rarity.legendary")
+    }
+  }
+}
+
+impl Display for Rarity {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} {:?}", self.icon(), self)
+  }
+}
+
+impl Display for Localized<'_, Rarity> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} {}", self.0.icon(), self.0.localized_name(self.1))
+  }
+}
+
+pub struct Pack {
+  pub name: &'static str,
+  pub description: &'static str,
+  pub pool: &'static [&'static str],
+  pub slots: &'static [Rarity],
+  pub items: &'static [&'static str]
+}
+
+pub static PACKS: [Pack; 3] = [
+  Pack { name: "Starter", description: "A handful of creatures to get going.",
+    pool: &["Slime", "Leech", "Bat"],
+    slots: &[Rarity::Common, Rarity::Common, Rarity::Common, Rarity::Uncommon],
+    items: &["Iron Helmet", "Leather Vest"] },
+  Pack { name: "Ooze", description: "Slimes and leeches, with a guaranteed rare.",
+    pool: &["Slime", "Leech"],
+    slots: &[Rarity::Common, Rarity::Common, Rarity::Rare],
+    items: &["Venom Fang"] },
+  Pack { name: "Armory", description: "Gear for the creatures you already have.",
+    pool: &[],
+    slots: &[],
+    items: &["Iron Helmet", "Leather Vest", "Venom Fang", "Echo Bell"] }
+];
+
+pub fn get(name: &str) -> Option<&'static Pack> {
+  PACKS.iter().find(|pack| pack.name.eq_ignore_ascii_case(name))
+}
+
+impl Pack {
+  /// Roll a creature from the pool that is at least as rare as the slot
+  fn roll_slot(&self, builders: &[EntityBuilder], slot: Rarity) -> (Entity, Rarity) {
+    let mut best: Option<(Entity, Rarity)> = None;
+
+    for _ in 0..MAX_ROLLS {
+      let builder = builders.choose(&mut rand::thread_rng()).unwrap();
+      let entity = builder.build();
+      let rarity = Rarity::of(builder, &entity);
+
+      if rarity >= slot {
+        return (entity, rarity);
+      }
+      if best.as_ref().is_none_or(|(_, r)| rarity > *r) {
+        best = Some((entity, rarity));
+      }
+    }
+
+    best.unwrap()
+  }
+
+  pub fn open(&self) -> Vec<(Entity, Rarity)> {
+    let builders: Vec<EntityBuilder> = self.pool.iter().filter_map(|name| templates::get(name)).collect();
+    if builders.is_empty() {
+      return Vec::new();
+    }
+
+    self.slots.iter().map(|slot| self.roll_slot(&builders, *slot)).collect()
+  }
+
+  /// A card for one of the attacks or abilities the creatures pulled from the pack know
+  pub fn roll_card(creatures: &[(Entity, Rarity)]) -> Option<Card> {
+    let cards: Vec<Card> = creatures.iter()
+      .flat_map(|(entity, _)| entity.attacks.iter().map(|attack| Card::Attack(attack))
+        .chain(entity.abilities.iter().map(|ability| Card::Ability(ability))))
+      .collect();
+    cards.choose(&mut rand::thread_rng()).copied()
+  }
+
+  /// A single random item from the pack's item list
+  pub fn roll_item(&self) -> Option<&'static Equipment> {
+    self.items.choose(&mut rand::thread_rng()).and_then(|name| equipment::get(name))
+  }
+}
+
+impl Display for Pack {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Pack> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(pack, locale) = *self;
+    let slots: Vec<String> = pack.slots.iter().map(|slot| slot.icon().to_string()).collect();
+    let card = if pack.slots.is_empty() { "" } else { locale.get("pack.card") };
+    let item = if pack.items.is_empty() { "" } else { locale.get("pack.item") };
+    writeln!(f, "{}", tr!(locale, "pack.summary", pack.name, slots.join(""), card, item, pack.description))
+  }
+}
@@ -1,15 +1,53 @@
-use crate::entity::{Entity, AbilityTrigger, Ability, ability::{AbilityTriggerType,
-  trigger_target::{ITSELF, ALLY, OPPONENT}, AbilityEffectTarget}};
+use std::collections::HashMap;
+use std::time::Instant;
+use rand::{SeedableRng, rngs::StdRng, seq::SliceRandom};
+use uuid::Uuid;
+
+use crate::entity::{Entity, Attack, AbilityTrigger, Ability, TriggerEvent, roll, ability::{AbilityTriggerType,
+  trigger_target::{ITSELF, ALLY, OPPONENT, ALL}, AbilityEffectTarget, EffectKind}};
+use crate::entity::aura::Aura;
+use crate::entity::stats::{DamageSource, Stat};
+use crate::entity::summon::Summon;
+use crate::entity::templates;
+use crate::entity::attack::{AttackShape, crit_damage, splash_damage};
+use crate::entity::state::{Status, StatusEffect, CONFUSION_CHANCE};
+use crate::entity::growth::{BATTLE_EXPERIENCE, VICTORY_EXPERIENCE};
+use crate::entity::consumable::{self, Consumable, STARTING_INVENTORY};
+use crate::encounter::{self, Difficulty, SpawnTable, SOLO_THREAT};
+use crate::locale::{Locale, Localized};
+use crate::tr;
+pub mod deck;
+use deck::{Deck, Card};
+pub mod ruleset;
+use ruleset::Ruleset;
+
+/// Team names available to commands registering entities
+pub const TEAM_NAMES: [&str; 4] = ["A", "B", "C", "D"];
 
 #[allow(dead_code, non_snake_case)]
 pub struct Scene {
-  pub teams: Vec<(&'static str, Vec<Entity>)>
+  pub teams: Vec<(&'static str, Vec<Entity>)>,
+  pub decks: HashMap<&'static str, Deck>,
+  pub inventories: HashMap<&'static str, Vec<&'static Consumable>>,
+  pub turn: usize,
+  pub finished: bool,
+  /// Entities as they were when the battle ended, waiting for their progress to be saved, see `take_finished_entities`
+  pub finished_entities: Vec<Entity>,
+  /// Language of the narration, set by commands from whoever is acting on the scene
+  pub locale: Locale,
+  /// Every roll made during battles, seed it to replay a battle exactly
+  pub rng: StdRng,
+  /// Rules of the battle, from friendly fire to how the damage formula rounds damage
+  pub ruleset: &'static Ruleset,
+  /// When the current team's turn started, for rulesets limiting how long a turn lasts
+  pub turn_started: Instant
 }
 
 
 impl Scene {
   pub fn new() -> Self {
-    Scene { teams: Vec::new() }
+    Scene { teams: Vec::new(), decks: HashMap::new(), inventories: HashMap::new(), turn: 0, finished: false,
+      finished_entities: Vec::new(), locale: Locale::English, rng: StdRng::from_entropy(), ruleset: ruleset::standard(), turn_started: Instant::now() }
   }
   
   #[allow(non_snake_case)]
@@ -25,7 +63,7 @@ impl Scene {
 
     let team_index = self.teams.len() as u8;
 
-    if team_index > 4 {
+    if team_index >= 4 {
       panic!("Team non available for registration.");
     }
 
@@ -33,142 +71,678 @@ impl Scene {
     team_index
   }
 
+  /// Check whether the ruleset leaves room for another entity on `team`, to be called before registering it
+  pub fn check_room(&self, team: &str) -> Result<(), String> {
+    match self.teams.iter().find(|(name, _)| *name == team) {
+      Some((_, entities)) if entities.len() >= self.ruleset.max_team_size => Err(tr!(self.locale, "summon.team_full", team)),
+      None if self.teams.len() >= self.ruleset.max_teams =>
+        Err(tr!(self.locale, "ruleset.no_more_teams", self.ruleset.name, self.ruleset.max_teams)),
+      _ => Ok(())
+    }
+  }
+
+  /// Fill `team` with creatures from `table`, as threatening as every other team still standing scaled by `difficulty`
+  pub fn encounter(&mut self, table: &SpawnTable, difficulty: Difficulty, team: &'static str) -> String {
+    let locale = self.locale;
+    if let Err(result) = self.check_room(team) {
+      return result;
+    }
+
+    let others: f32 = self.teams.iter().filter(|(name, _)| *name != team)
+      .flat_map(|(_, entities)| entities.iter().filter(|e| e.is_alive()))
+      .map(encounter::threat).sum();
+    let target = if others <= 0.0 { SOLO_THREAT } else { others } * difficulty.threat_percent() / 100.0;
+    let registered = self.teams.iter().find(|(name, _)| *name == team).map_or(0, |(_, entities)| entities.len());
+    let entities = table.generate(difficulty, target, self.ruleset.max_team_size - registered, &mut self.rng);
+    if entities.is_empty() {
+      return tr!(locale, "encounter.empty", table.name);
+    }
+
+    let threat: f32 = entities.iter().map(encounter::threat).sum();
+    println!("A {:?} encounter from the \"{}\" joined team \"{}\" (threat {:.1}/{:.1})", difficulty, table.name, team, threat,
+      target);
+    let mut result = tr!(locale, "encounter.appeared", table.name, Localized(&difficulty, locale), team,
+      format!("{:.1}", threat), format!("{:.1}", target));
+    for entity in entities {
+      let id = self.register(team, &entity);
+      result += &tr!(locale, "encounter.joined", entity.name, id);
+      result += &self.enter(id);
+    }
+
+    result
+  }
+
+  /// Check whether the ruleset lets `attacker` aim at `target`
+  fn check_target(&self, attacker: u8, target: u8) -> Result<(), String> {
+    let (e, t) = (self.get_entity_from_id(attacker).unwrap(), self.get_entity_from_id(target).unwrap());
+    if attacker == target && !self.ruleset.self_targeting {
+      return Err(tr!(self.locale, "ruleset.self_targeting_denied", e.name, attacker, self.ruleset.name));
+    }
+    if attacker != target && attacker & 3 == target & 3 && !self.ruleset.friendly_fire {
+      return Err(tr!(self.locale, "ruleset.friendly_fire_denied", e.name, attacker, t.name, target, self.ruleset.name));
+    }
+    Ok(())
+  }
+
   #[allow(dead_code)]
   pub fn attack(&mut self, attack_name: &str, attacker: u8, target: u8) -> String {
+    // Once cards are dealt attacks cost energy and follow turns, they can only be played from the hand
+    if !self.decks.is_empty() {
+      return self.locale.get("scene.cards_dealt").to_string();
+    }
+
     // Check if entities exist
-    if let None = self.get_entity_from_id(attacker) {
-      let result = format!("Could not find attacking entity with id #{}", attacker);
-      println!("{}", result);
-      return result;
+    if self.get_entity_from_id(attacker).is_none() {
+      println!("Could not find attacking entity with id #{}", attacker);
+      return tr!(self.locale, "scene.no_attacker", attacker);
     }
-    if let None = self.get_mut_entity_from_id(target) {
-      let result = format!("Could not find target entity with id #{}", target);
-      println!("{}", result);
-      return result;
+    if self.get_entity_from_id(target).is_none() {
+      println!("Could not find target entity with id #{}", target);
+      return tr!(self.locale, "scene.no_target", target);
     }
 
     // Get entities
-    let mut e = self.get_mut_entity_from_id(attacker).unwrap();
+    let e = self.get_entity_from_id(attacker).unwrap();
 
     // Check if attack exists
-    if let None = e.get_attack(attack_name) { 
+    if e.get_attack(attack_name).is_none() {
       println!("\"{}\"#{} does not know the \"{}\" attack", e.name, attacker, attack_name);
-      return format!("**{}**#{} does not know the `{}` attack", e.name, attacker, attack_name);
+      return tr!(self.locale, "scene.unknown_attack", e.name, attacker, attack_name);
     }
 
+    // Get attack
+    let attack = e.get_attack(attack_name).unwrap();
 
+    if let Err(result) = self.check_target(attacker, target) {
+      return result;
+    }
 
-    // Get attack
-    let attack = e.get_attack(attack_name).unwrap().clone();
+    self.use_attack(attack, attacker, target)
+  }
+
+  /// Resolve an attack between two existing entities, the attacker is assumed to know the attack
+  fn use_attack(&mut self, attack: Attack, attacker: u8, mut target: u8) -> String {
+    let locale = self.locale;
+    let mut e = self.get_mut_entity_from_id(attacker).unwrap();
 
-    // Check if target is alive
+    // Check if attacker is alive
     if !e.is_alive() {
       println!("\"{}\"#{} tried using \"{}\" but was unconscious", e.name, attacker, attack.name);
-      return format!("**{}**#{} tried using `{}` but was unconscious!", e.name, attacker, attack.name);
+      return tr!(locale, "attack.unconscious_attacker", e.name, attacker, attack.name);
+    }
+    if let Some(status) = e.incapacitated_by() {
+      println!("\"{}\"#{} cannot act because of {}", e.name, attacker, status);
+      return tr!(locale, "status.cannot_act", e.name, attacker, Localized(&status, locale));
     }
 
     e = self.get_mut_entity_from_id(target).unwrap();
     if !e.is_alive() {
       println!("\"{}\"#{} was targetted by the \"{}\" attack but is already unconscious", e.name, target, attack.name);
-      return format!("**{}**#{} was targetted by the `{}` attack but is already unconscious!", e.name, target, attack.name);
+      return tr!(locale, "attack.unconscious_target", e.name, target, attack.name);
     }
 
     // Variables
     let mut result = String::new();
     let mut ability_queue: Vec<(Ability, u8, u8)> = Vec::new();
-    let dmg_taken: u8;
+    // Entities stay borrowed from the scene while abilities roll, the rng is put back once they are done
+    let mut rng = self.rng.clone();
 
-    e = self.get_mut_entity_from_id(attacker).unwrap();
-    println!("\"{}\"#{} is using the \"{}\" attack", e.name, attacker, attack.name);
-    result += &format!("**{}**#{} is using the `{}` attack", e.name, attacker, attack.name);
-    e = self.get_mut_entity_from_id(target).unwrap();
-    println!(" on \"{}\"#{}", e.name, target);
-    result += &format!(" on **{}**#{}\n", e.name, target);
-    
+    // Confused attackers might turn on one of their allies instead, or themselves, as far as the ruleset allows it
+    if self.get_entity_from_id(attacker).unwrap().has_status(Status::Confuse) {
+      let allies: Vec<u8> = self.living_ids().into_iter()
+        .filter(|id| id & 3 == attacker & 3 && *id != attacker && self.check_target(attacker, *id).is_ok()).collect();
+      let fallback = self.check_target(attacker, attacker).ok().map(|_| attacker);
+      let retarget = allies.choose(&mut rng).copied().or(fallback);
+      if let (Some(retarget), Some((true, rolled))) = (retarget, roll(&mut rng, CONFUSION_CHANCE)) {
+        target = retarget;
+        println!("\"{}\"#{} is confused and attacks #{} instead", self.get_entity_from_id(attacker).unwrap().name, attacker, target);
+        result += &tr!(locale, "status.confused", rolled, CONFUSION_CHANCE,
+          self.get_entity_from_id(attacker).unwrap().name, attacker);
+      }
+    }
 
-    // Deal damage to target and check for triggered abilities
-    if attack.t.damage_on_hit() {
-      let (x, y) = e.take_damage(attack.damage, attack.t);
-      result += &x;
-      dmg_taken = y;
+    let attacker_name = self.get_entity_from_id(attacker).unwrap().name;
+    let targets = self.attack_targets(attack.shape, attacker, target, &mut rng);
+    if let AttackShape::Single = attack.shape {
+      let target_name = self.get_entity_from_id(target).unwrap().name;
+      println!("\"{}\"#{} is using the \"{}\" attack on \"{}\"#{}", attacker_name, attacker, attack.name, target_name, target);
+      result += &tr!(locale, "attack.using", attacker_name, attacker, attack.name, target_name, target);
+    }
+    else if targets.is_empty() {
+      println!("\"{}\"#{} used the \"{}\" attack but nobody was there to hit", attacker_name, attacker, attack.name);
+      self.rng = rng;
+      return tr!(locale, "attack.no_targets", attacker_name, attacker, attack.name);
+    }
+    else {
+      let names: Vec<String> = targets.iter()
+        .map(|id| format!("**{}**#{}", self.get_entity_from_id(*id).unwrap().name, id)).collect();
+      println!("\"{}\"#{} is using the \"{}\" attack on {:?}", attacker_name, attacker, attack.name, targets);
+      result += &tr!(locale, "attack.using_shape", attacker_name, attacker, attack.name, names.join(", "));
+    }
 
-      // target triggers
-      if attacker != target {
-        let source: u8;
-        if attacker & 3 == target & 3 {
-          source = ALLY;
+    // Deal damage to every target and check for triggered abilities
+    let damage = self.get_entity_from_id(attacker).unwrap().attack_damage(&attack);
+    for id in targets {
+      let damage = if attack.shape == AttackShape::Splash && id != target { splash_damage(damage) } else { damage };
+      result += &self.strike(&attack, attacker, id, damage, &mut ability_queue, &mut rng);
+    }
+
+    if let Some(summon) = attack.summon {
+      result += &self.summon(summon, attacker, &mut rng);
+    }
+
+    // Resolve triggered abilities
+    result += &self.resolve_abilities(ability_queue, &mut rng);
+
+    self.rng = rng;
+    result += &self.resolve_deaths();
+
+    result
+  }
+
+  /// Entities hit by an attack used by `attacker` on `target`, depending on the attack's shape
+  fn attack_targets(&self, shape: AttackShape, attacker: u8, target: u8, rng: &mut StdRng) -> Vec<u8> {
+    let living = self.living_ids();
+    match shape {
+      AttackShape::Single => vec![target],
+      AttackShape::AllOpponents => living.into_iter().filter(|id| id & 3 != attacker & 3).collect(),
+      AttackShape::AllAllies if !self.ruleset.friendly_fire => Vec::new(),
+      AttackShape::AllAllies => living.into_iter().filter(|id| id & 3 == attacker & 3 && *id != attacker).collect(),
+      AttackShape::Random(amount) => {
+        let opponents: Vec<u8> = living.into_iter().filter(|id| id & 3 != attacker & 3).collect();
+        opponents.choose_multiple(rng, amount as usize).copied().collect()
+      },
+      // Entity IDs keep registration order within a team, neighbours are one index away
+      AttackShape::Splash => {
+        let mut result = vec![target];
+        result.extend(living.into_iter().filter(|id| id & 3 == target & 3 && (id >> 2).abs_diff(target >> 2) == 1));
+        result
+      }
+    }
+  }
+
+  /// Roll whether an attack hits a single target, then deal `damage` to it and queue the abilities it triggers
+  fn strike(&mut self, attack: &Attack, attacker: u8, target: u8, damage: u8,
+    ability_queue: &mut Vec<(Ability, u8, u8)>, rng: &mut StdRng) -> String {
+    let locale = self.locale;
+    let mut result = String::new();
+
+    // Evasion lowers the attack's accuracy, rolls between the two are dodges rather than misses
+    let target_entity = self.get_entity_from_id(target).unwrap();
+    let hit_chance = attack.hit_chance.saturating_sub(target_entity.stat(Stat::Evasion));
+    if let Some((hit, rolled)) = roll(rng, hit_chance) {
+      if !hit {
+        let target_name = target_entity.name;
+        if rolled <= attack.hit_chance {
+          println!("\"{}\"#{} dodged \"{}\" ({} > {}%)", target_name, target, attack.name, rolled, hit_chance);
+          result += &tr!(locale, "attack.dodged", target_name, target, attack.name, rolled, hit_chance);
         }
         else {
-          source = OPPONENT;
+          println!("\"{}\" missed \"{}\"#{} ({} > {}%)", attack.name, target_name, target, rolled, hit_chance);
+          result += &tr!(locale, "attack.missed", target_name, target, attack.name, rolled, hit_chance);
         }
-
-        result += &e.check_for_trigger(AbilityTrigger { t: AbilityTriggerType::AnyDamage, source, target: ITSELF },
-          &mut ability_queue, attacker, target);
-        result += &e.check_for_trigger(AbilityTrigger { t: AbilityTriggerType::Damage(attack.t), source, target: ITSELF },
-          &mut ability_queue, attacker, target);
+        return result;
       }
+      result += &tr!(locale, "attack.hit", rolled, hit_chance);
+    }
 
-      // Attacker triggers
-      e = self.get_mut_entity_from_id(attacker).unwrap();
-      let trigger_target: u8;
-      if attacker == target {
-        trigger_target = ITSELF;
+    let mut damage = damage;
+    if attack.crit_chance > 0 {
+      let crit = match roll(rng, attack.crit_chance) {
+        Some((crit, rolled)) => {
+          if crit {
+            result += &tr!(locale, "attack.critical_roll", rolled, attack.crit_chance);
+          }
+          crit
+        },
+        None => {
+          result += locale.get("attack.critical");
+          true
+        }
+      };
+      if crit {
+        damage = crit_damage(damage, self.ruleset.crit_multiplier);
+        println!("\"{}\" is a critical hit, dealing {} damage", attack.name, damage);
       }
-      else if attacker & 3 == target & 3 {
-        trigger_target = ALLY;
+    }
+
+    let source = DamageSource::from_stats(self.get_entity_from_id(attacker).unwrap().current_stats(), self.ruleset.damage);
+    if attack.t.damage_on_hit() {
+      let auras = self.auras_on(target);
+      let mut e = self.get_mut_entity_from_id(target).unwrap();
+      let first_hit = !e.damaged_this_turn;
+      let (x, dmg_taken) = e.take_damage(damage, attack.t, source, &auras, locale);
+      if attacker != target {
+        e.last_attacker = Some(attacker);
       }
-      else {
-        trigger_target = OPPONENT;
+      result += &x;
+      let event = TriggerEvent { source: attacker, target, damage: dmg_taken,
+        kills: e.is_alive() && e.current_health == 0, first_hit };
+
+      // target triggers, seen from the target's side
+      if attacker != target {
+        let source = if attacker & 3 == target & 3 { ALLY } else { OPPONENT };
+        let event = TriggerEvent { source: target, target: attacker, ..event };
+
+        result += &e.check_for_trigger(AbilityTrigger { t: AbilityTriggerType::AnyDamage, source, target: ITSELF, conditions: &[] },
+          ability_queue, &event, rng, locale);
+        result += &e.check_for_trigger(AbilityTrigger { t: AbilityTriggerType::Damage(attack.t), source, target: ITSELF, conditions: &[] },
+          ability_queue, &event, rng, locale);
       }
 
+      // Attacker triggers
+      e = self.get_mut_entity_from_id(attacker).unwrap();
+      let trigger_target = if attacker == target { ITSELF }
+        else if attacker & 3 == target & 3 { ALLY }
+        else { OPPONENT };
+
       result += &e.check_for_trigger(AbilityTrigger {
-        t: AbilityTriggerType::AnyDamage, source: ITSELF, target: trigger_target },
-        &mut ability_queue, attacker, target );
+        t: AbilityTriggerType::AnyDamage, source: ITSELF, target: trigger_target, conditions: &[] },
+        ability_queue, &event, rng, locale);
       result += &e.check_for_trigger(AbilityTrigger {
-        t: AbilityTriggerType::Damage(attack.t), source: ITSELF, target: trigger_target },
-        &mut ability_queue, attacker, target);
+        t: AbilityTriggerType::Damage(attack.t), source: ITSELF, target: trigger_target, conditions: &[] },
+        ability_queue, &event, rng, locale);
 
 
       // Check for vamp healing
       if attack.t.is_vampiric() {
-        let healed_amt = e.heal(dmg_taken);
-        println!("\"{}\" is being healed for {} ❤️ ", e.name, dmg_taken);
+        let healing = self.ruleset.vampiric_healing(dmg_taken);
+        let e = self.get_mut_entity_from_id(attacker).unwrap();
+        let healed_amt = e.heal(healing);
+        println!("\"{}\" is being healed for {} ❤️ ", e.name, healing);
         println!("\"{}\" got healed by {} ❤️ ", e.name, healed_amt);
-        result += &format!("**{}** is being healed for {} ❤️ \n", e.name, dmg_taken);
-        result += &format!("**{}** got healed by {} ❤️ \n", e.name, healed_amt);
+        result += &tr!(locale, "heal.healing", e.name, healing);
+        result += &tr!(locale, "heal.healed", e.name, healed_amt);
+      }
+    }
+    else if attack.t.stacks() {
+      let auras = self.auras_on(target);
+      let e = self.get_mut_entity_from_id(target).unwrap();
+      if attacker != target {
+        e.last_attacker = Some(attacker);
       }
+      result += &e.add_stacks(damage, attack.t, source, &auras, locale);
     }
 
-    
-    // Resolve triggered abilities
+    if let Some(effect) = attack.status {
+      result += &self.inflict(effect, target, rng);
+    }
+
+    result
+  }
+
+  /// Apply the effects of triggered abilities, each queued with the entity holding it and the other entity involved
+  fn resolve_abilities(&mut self, ability_queue: Vec<(Ability, u8, u8)>, rng: &mut StdRng) -> String {
+    let mut result = String::new();
+
     for (ability, source, target) in ability_queue {
-      let e: &mut Entity;
-      match ability.effect.target {
-        AbilityEffectTarget::This => {
-          e = self.get_mut_entity_from_id(source).unwrap();
-          result += &e.take_damage(ability.effect.damage, ability.effect.t).0;
-        },
-        AbilityEffectTarget::TriggerTarget => {
-          e = self.get_mut_entity_from_id(target).unwrap();
-          result += &e.take_damage(ability.effect.damage, ability.effect.t).0;
-        },
-        _ => {}
+      for effect in ability.effects.iter() {
+        // Summons always join the team of the entity holding the ability
+        if let EffectKind::Summon(summon) = effect.kind {
+          result += &self.summon(summon, source, rng);
+          continue;
+        }
+
+        // Random and area targets are picked among the living allies or opponents of the entity holding the ability
+        let allies: Vec<u8> = self.living_ids().into_iter().filter(|id| id & 3 == source & 3 && *id != source).collect();
+        let opponents: Vec<u8> = self.living_ids().into_iter().filter(|id| id & 3 != source & 3).collect();
+        let ids = match effect.target {
+          AbilityEffectTarget::This => vec![source],
+          AbilityEffectTarget::TriggerTarget => vec![target],
+          AbilityEffectTarget::AnyAlly => allies.choose(rng).copied().into_iter().collect(),
+          AbilityEffectTarget::AnyOpponent => opponents.choose(rng).copied().into_iter().collect(),
+          AbilityEffectTarget::AllAlly => allies,
+          AbilityEffectTarget::AllOpponent => opponents
+        };
+
+        for id in ids {
+          result += &self.apply_ability_effect(effect.kind, source, id, rng);
+        }
+      }
+    }
+
+    result
+  }
+
+  /// Register the entities of `summon` on the team of `summoner` as long as limits allow, then resolve their arrival
+  fn summon(&mut self, summon: Summon, summoner: u8, rng: &mut StdRng) -> String {
+    let locale = self.locale;
+    let mut result = String::new();
+    let builder = match templates::get(summon.template) {
+      Some(builder) => builder,
+      None => return result
+    };
+    let team_index = (summoner & 3) as usize;
+    let team = self.teams[team_index].0;
+    let summoner_name = self.get_entity_from_id(summoner).unwrap().name;
+
+    let mut ids = Vec::new();
+    for _ in 0..summon.count {
+      let standing = self.teams[team_index].1.iter()
+        .filter(|e| e.is_alive() && e.summoner == Some(summoner) && e.name == builder.name).count();
+      if standing >= summon.max as usize {
+        println!("\"{}\"#{} cannot have more than {} \"{}\" at once", summoner_name, summoner, summon.max, builder.name);
+        result += &tr!(locale, "summon.limit", summoner_name, summoner, builder.name, summon.max);
+        break;
+      }
+      if let Err(full) = self.check_room(team) {
+        println!("Team \"{}\" is full", team);
+        result += &full;
+        break;
       }
+
+      let mut entity = builder.build_with(rng);
+      entity.summoner = Some(summoner);
+      let id = self.register(team, &entity);
+      println!("\"{}\"#{} summoned \"{}\"#{} on team \"{}\"", summoner_name, summoner, entity.name, id, team);
+      result += &tr!(locale, "summon.summoned", summoner_name, summoner, entity.name, id, team);
+      ids.push(id);
+    }
+
+    let mut ability_queue: Vec<(Ability, u8, u8)> = Vec::new();
+    result += &self.queue_self_triggers(AbilityTriggerType::EnterScene, ids, &mut ability_queue, rng);
+    result += &self.resolve_abilities(ability_queue, rng);
+
+    result
+  }
+
+  /// Apply one effect of a triggered ability held by `source` to the entity `id`
+  fn apply_ability_effect(&mut self, kind: EffectKind, source: u8, id: u8, rng: &mut StdRng) -> String {
+    let locale = self.locale;
+    let mut result = String::new();
+
+    let auras = self.auras_on(id);
+    let damage_source = DamageSource::from_stats(self.get_entity_from_id(source).unwrap().current_stats(), self.ruleset.damage);
+    let e = self.get_mut_entity_from_id(id).unwrap();
+    match kind {
+      EffectKind::Damage(t, amount) => {
+        if source != id {
+          e.last_attacker = Some(source);
+        }
+        result += &e.take_damage(amount, t, damage_source, &auras, locale).0;
+      },
+      EffectKind::Stacks(t, amount) => {
+        if source != id {
+          e.last_attacker = Some(source);
+        }
+        result += &e.add_stacks(amount, t, damage_source, &auras, locale);
+      },
+      EffectKind::Heal(amount) => {
+        let healed_amt = e.heal(amount);
+        println!("\"{}\" got healed by {} ❤️ ", e.name, healed_amt);
+        result += &tr!(locale, "heal.healed", e.name, healed_amt);
+      },
+      EffectKind::Revive(percent) => {
+        if !e.is_alive() {
+          let health = e.revive(percent);
+          println!("\"{}\" got back up with {} ❤️ ", e.name, health);
+          result += &tr!(locale, "heal.revived", e.name, health);
+        }
+      },
+      EffectKind::Modify(modifier, turns) => result += &e.add_modifier(modifier, turns, locale),
+      EffectKind::Status(effect) => result += &self.inflict(effect, id, rng),
+      EffectKind::Summon(summon) => result += &self.summon(summon, source, rng)
+    }
+
+    result
+  }
+
+  /// Fire the abilities of the living entities of the team `team_index` reacting to a phase of its turn
+  fn check_phase_triggers(&mut self, t: AbilityTriggerType, team_index: usize) -> String {
+    let ids = self.living_ids().into_iter().filter(|id| (id & 3) as usize == team_index).collect();
+    self.check_self_triggers(t, ids)
+  }
+
+  /// Fire the abilities of an entity that just joined the scene, to be called once it is registered
+  pub fn enter(&mut self, id: u8) -> String {
+    self.check_self_triggers(AbilityTriggerType::EnterScene, vec![id])
+  }
+
+  /// Check triggers of entities `ids` that only concern themselves, then resolve whatever went off
+  fn check_self_triggers(&mut self, t: AbilityTriggerType, ids: Vec<u8>) -> String {
+    let mut rng = self.rng.clone();
+    let mut ability_queue: Vec<(Ability, u8, u8)> = Vec::new();
+
+    let mut result = self.queue_self_triggers(t, ids, &mut ability_queue, &mut rng);
+    if ability_queue.is_empty() {
+      self.rng = rng;
+      return result;
     }
 
-    // Resolve any deaths that occured after the queued attacks
-    for (_, team) in self.teams.iter_mut() {
-      for entity in team.iter_mut() {
+    result += &self.resolve_abilities(ability_queue, &mut rng);
+    self.rng = rng;
+    result += &self.resolve_deaths();
+
+    result
+  }
+
+  /// Queue the abilities of living entities `ids` whose trigger only concerns themselves
+  fn queue_self_triggers(&mut self, t: AbilityTriggerType, ids: Vec<u8>, ability_queue: &mut Vec<(Ability, u8, u8)>,
+    rng: &mut StdRng) -> String {
+    let locale = self.locale;
+    let mut result = String::new();
+
+    for id in ids {
+      if let Some(e) = self.get_mut_entity_from_id(id).filter(|e| e.is_alive()) {
+        let event = TriggerEvent { source: id, target: id, damage: 0, kills: false, first_hit: false };
+        result += &e.check_for_trigger(AbilityTrigger { t, source: ITSELF, target: ITSELF, conditions: &[] },
+          ability_queue, &event, rng, locale);
+      }
+    }
+
+    result
+  }
+
+  /// Roll whether a status effect lands on the entity `id` and inflict it
+  fn inflict(&mut self, effect: StatusEffect, id: u8, rng: &mut StdRng) -> String {
+    let locale = self.locale;
+    let mut result = String::new();
+
+    if let Some((success, rolled)) = roll(rng, effect.chance) {
+      if !success {
+        return result;
+      }
+      result += &tr!(locale, "status.roll", rolled, effect.chance);
+    }
+    if let Some(e) = self.get_mut_entity_from_id(id) {
+      result += &e.add_status(effect.status, effect.turns, locale);
+    }
+
+    result
+  }
+
+  /// Mark entities with no health left as fainted, resolve the abilities reacting to it
+  /// and check whether that ended the battle
+  fn resolve_deaths(&mut self) -> String {
+    let mut result = String::new();
+    let mut rng = self.rng.clone();
+
+    // Abilities going off as entities faint can knock out or revive others in turn
+    for _ in 0..self.ruleset.chain_depth {
+      let fainted = self.mark_fainted(&mut result);
+      if fainted.is_empty() {
+        break;
+      }
+
+      let mut ability_queue: Vec<(Ability, u8, u8)> = Vec::new();
+      for (id, killer) in fainted {
+        result += &self.check_faint_triggers(id, killer, &mut ability_queue, &mut rng);
+      }
+      result += &self.resolve_abilities(ability_queue, &mut rng);
+    }
+    // Whoever the last round of the chain knocked out still faints, nothing gets to react to it anymore
+    self.mark_fainted(&mut result);
+
+    self.rng = rng;
+    result += &self.check_battle_end();
+
+    result
+  }
+
+  /// Mark every entity with no health left as fainted, returns their IDs along with whoever knocked them out
+  fn mark_fainted(&mut self, result: &mut String) -> Vec<(u8, Option<u8>)> {
+    let mut fainted = Vec::new();
+    for (team_index, (_, team)) in self.teams.iter_mut().enumerate() {
+      for (index, entity) in team.iter_mut().enumerate() {
         if entity.died() {
           println!("\"{}\" has fainted!", entity.name);
-          result += &format!("**{}** has fainted!", entity.name);
+          *result += &tr!(self.locale, "scene.fainted", entity.name);
+          fainted.push(((index << 2 | team_index) as u8, entity.last_attacker));
         }
       }
     }
+    fainted
+  }
+
+  /// Check the abilities reacting to the entity `id` fainting: its own last stand, its allies' and its killer's.
+  /// Fainting triggers target the killer when it is known, `OnKill` targets the fainted entity.
+  fn check_faint_triggers(&mut self, id: u8, killer: Option<u8>, ability_queue: &mut Vec<(Ability, u8, u8)>,
+    rng: &mut StdRng) -> String {
+    let locale = self.locale;
+    let mut result = String::new();
+    let relation = |holder: u8, other: u8| if holder == other { ITSELF }
+      else if holder & 3 == other & 3 { ALLY }
+      else { OPPONENT };
+    let source_of = |holder: u8| killer.map_or(ALL, |killer| relation(holder, killer));
+    let killer_or_self = killer.unwrap_or(id);
+
+    // Last stand
+    let e = self.get_mut_entity_from_id(id).unwrap();
+    let event = TriggerEvent { source: id, target: killer_or_self, damage: 0, kills: true, first_hit: false };
+    result += &e.check_for_trigger(AbilityTrigger { t: AbilityTriggerType::OnFaint, source: source_of(id), target: ITSELF,
+      conditions: &[] }, ability_queue, &event, rng, locale);
+
+    // Allies still standing
+    let team = (id & 3) as usize;
+    let allies: Vec<u8> = self.teams[team].1.iter().enumerate()
+      .filter(|(_, e)| e.is_alive())
+      .map(|(index, _)| (index << 2 | team) as u8).collect();
+    for ally in allies {
+      let e = self.get_mut_entity_from_id(ally).unwrap();
+      let event = TriggerEvent { source: ally, target: killer_or_self, damage: 0, kills: true, first_hit: false };
+      result += &e.check_for_trigger(AbilityTrigger { t: AbilityTriggerType::OnAllyFaint, source: source_of(ally),
+        target: ALLY, conditions: &[] }, ability_queue, &event, rng, locale);
+    }
+
+    // Killer
+    if let Some(killer) = killer.filter(|killer| *killer != id) {
+      if let Some(e) = self.get_mut_entity_from_id(killer).filter(|e| e.is_alive()) {
+        let event = TriggerEvent { source: killer, target: id, damage: 0, kills: true, first_hit: false };
+        result += &e.check_for_trigger(AbilityTrigger { t: AbilityTriggerType::OnKill, source: ITSELF,
+          target: relation(killer, id), conditions: &[] }, ability_queue, &event, rng, locale);
+      }
+    }
 
     result
   }
 
+  /// End the battle once at most one team is still standing, rewarding every participant with experience
+  fn check_battle_end(&mut self) -> String {
+    if self.finished || self.teams.len() < 2 {
+      return String::new();
+    }
+
+    let standing: Vec<usize> = self.teams.iter().enumerate()
+      .filter(|(_, (_, entities))| entities.iter().any(|e| e.is_alive()))
+      .map(|(index, _)| index).collect();
+    if standing.len() > 1 {
+      return String::new();
+    }
+
+    self.finished = true;
+    let locale = self.locale;
+    let mut result = String::new();
+    match standing.first() {
+      Some(index) => {
+        println!("Team \"{}\" won the battle!", self.teams[*index].0);
+        result += &tr!(locale, "scene.victory", self.teams[*index].0);
+      },
+      None => {
+        println!("Every team has fainted, the battle is a draw!");
+        result += locale.get("scene.draw");
+      }
+    }
+
+    for (team_index, (_, entities)) in self.teams.iter_mut().enumerate() {
+      let experience = if standing.contains(&team_index) { BATTLE_EXPERIENCE + VICTORY_EXPERIENCE }
+        else { BATTLE_EXPERIENCE };
+      for entity in entities.iter_mut() {
+        result += &entity.gain_experience(experience, &mut self.rng, locale);
+      }
+    }
+    self.finished_entities = self.entities().cloned().collect();
+
+    result
+  }
+
+  /// Evolve an entity in the middle of the battle, it keeps its ID in the scene
+  pub fn evolve(&mut self, id: u8, item: Option<&str>) -> String {
+    let locale = self.locale;
+    let mut rng = self.rng.clone();
+    let e = match self.get_mut_entity_from_id(id) {
+      Some(e) => e,
+      None => return tr!(locale, "scene.no_entity", id)
+    };
+
+    let result = match e.evolved(item, &mut rng) {
+      Some(evolved) => {
+        println!("\"{}\"#{} evolved into \"{}\"!", e.name, id, evolved.name);
+        let result = tr!(locale, "scene.evolved", e.name, id, evolved.name);
+        *e = evolved;
+        result
+      },
+      None => tr!(locale, "scene.cannot_evolve", e.name, id)
+    };
+    self.rng = rng;
+
+    result
+  }
+
+  /// Make every following roll reproducible
+  pub fn seed(&mut self, seed: u64) {
+    self.rng = StdRng::seed_from_u64(seed);
+  }
+
+  /// Auras of every living entity in the scene that apply to the entity `id`
+  pub fn auras_on(&self, id: u8) -> Vec<&'static Aura> {
+    let mut result = Vec::new();
+
+    for (team_index, (_, entities)) in self.teams.iter().enumerate() {
+      for (entity_index, entity) in entities.iter().enumerate().filter(|(_, e)| e.is_alive()) {
+        let owner = (entity_index << 2 | team_index) as u8;
+        result.extend(entity.auras.iter().filter(|aura| aura.affects(owner, id)));
+      }
+    }
+
+    result
+  }
+
+  /// IDs of every entity in the scene that has not fainted
+  pub fn living_ids(&self) -> Vec<u8> {
+    let mut result = Vec::new();
+
+    for (team_index, (_, entities)) in self.teams.iter().enumerate() {
+      for (entity_index, _) in entities.iter().enumerate().filter(|(_, e)| e.is_alive()) {
+        result.push((entity_index << 2 | team_index) as u8);
+      }
+    }
+
+    result
+  }
+
+  /// Entities of a battle that just ended, handed out only once so their progress is saved a single time
+  pub fn take_finished_entities(&mut self) -> Vec<Entity> {
+    std::mem::take(&mut self.finished_entities)
+  }
+
+  pub fn entities(&self) -> impl Iterator<Item = &Entity> {
+    self.teams.iter().flat_map(|(_, entities)| entities.iter())
+  }
+
+  pub fn contains(&self, id: Uuid) -> bool {
+    self.entities().any(|entity| entity.id == id)
+  }
+
   pub fn get_mut_entity_from_id(&mut self, id: u8) -> Option<&mut Entity> {
     if let Some((_, entities)) = self.teams.get_mut((id & 3) as usize) {
       let index = (id >> 2) as usize;
@@ -196,7 +770,7 @@ impl Scene {
   #[allow(dead_code)]
   pub fn describe_entity(&self, id: u8) -> Option<String> {
     if let Some(entity) = self.get_entity_from_id(id) {
-      let result = format!("(#{})\n{}\n", id, entity);
+      let result = format!("(#{})\n{}\n", id, Localized(entity, self.locale));
       println!("{}", result);
       Some(result)
     }
@@ -211,23 +785,474 @@ impl Scene {
     let mut result = String::new();
 
     for (team_index, (name, entities)) in self.teams.iter().enumerate() {
-      result += &format!("Team \"{}\"\n", name);
+      result += &tr!(self.locale, "scene.team", name);
       for (entity_index, entity) in entities.iter().enumerate() {
         let id = (entity_index << 2 | team_index) as u8;
-        result += &format!("#{}\n{}\n", id, entity);
+        result += &format!("#{}\n{}\n", id, Localized(entity, self.locale));
       }
     }
 
+    let mut aura_descs = String::new();
+    for (team_index, (_, entities)) in self.teams.iter().enumerate() {
+      for (entity_index, entity) in entities.iter().enumerate().filter(|(_, e)| e.is_alive()) {
+        for aura in entity.auras.iter() {
+          aura_descs += &tr!(self.locale, "scene.aura_owner", entity.name, entity_index << 2 | team_index);
+          aura_descs += &Localized(*aura, self.locale).to_string();
+        }
+      }
+    }
+    if !aura_descs.is_empty() {
+      result += self.locale.get("scene.auras");
+      result += &aura_descs;
+    }
+
     result
   }
 
   #[allow(dead_code)]
   pub fn nuke(&mut self) {
     self.teams = Vec::new();
+    self.decks = HashMap::new();
+    self.inventories = HashMap::new();
+    self.turn = 0;
+    self.finished = false;
+    self.finished_entities = Vec::new();
+    self.ruleset = ruleset::standard();
   }
 
   #[allow(dead_code)]
   pub fn is_empty(&self) -> bool {
-    self.teams.len() == 0
+    self.teams.is_empty()
+  }
+
+  /// Build a deck for every team out of its members' attacks and abilities, then start the first turn.
+  /// The battle is played by `ruleset` if given, by the scene's current rules otherwise.
+  pub fn deal(&mut self, ruleset: Option<&'static Ruleset>) -> String {
+    if self.teams.is_empty() {
+      return self.locale.get("scene.empty").to_string();
+    }
+
+    let ruleset = ruleset.unwrap_or(self.ruleset);
+    if self.teams.len() > ruleset.max_teams {
+      return tr!(self.locale, "ruleset.too_many_teams", ruleset.name, ruleset.max_teams, self.teams.len());
+    }
+    if let Some((name, entities)) = self.teams.iter().find(|(_, entities)| entities.len() > ruleset.max_team_size) {
+      return tr!(self.locale, "ruleset.team_too_big", ruleset.name, ruleset.max_team_size, name, entities.len());
+    }
+    self.ruleset = ruleset;
+
+    self.decks = HashMap::new();
+    self.inventories = HashMap::new();
+    for (name, entities) in self.teams.iter() {
+      self.decks.insert(*name, Deck::from_entities(entities, &mut self.rng));
+      self.inventories.insert(*name, STARTING_INVENTORY.iter().filter_map(|item| consumable::get(item)).collect());
+    }
+
+    self.turn = self.next_standing_team(0);
+    self.finished = false;
+    self.finished_entities = Vec::new();
+    println!("Dealt decks to {} teams, playing by the \"{}\" rules", self.teams.len(), self.ruleset.name);
+    tr!(self.locale, "scene.dealt", self.teams.len(), self.start_turn(), self.ruleset.name)
+  }
+
+  pub fn current_team(&self) -> Option<&'static str> {
+    self.teams.get(self.turn).map(|(name, _)| *name)
+  }
+
+  /// First team from `from` on, wrapping around, with a creature still standing, teams knocked out lose their turns
+  fn next_standing_team(&self, from: usize) -> usize {
+    (0..self.teams.len()).map(|offset| (from + offset) % self.teams.len())
+      .find(|index| self.teams[*index].1.iter().any(Entity::is_alive))
+      .unwrap_or(from % self.teams.len())
+  }
+
+  fn start_turn(&mut self) -> String {
+    let team = match self.current_team() {
+      Some(team) => team,
+      None => return String::new()
+    };
+
+    if let Some(deck) = self.decks.get_mut(team) {
+      deck.start_turn(&mut self.rng);
+    }
+
+    println!("It is now team \"{}\"'s turn", team);
+    let mut result = tr!(self.locale, "scene.turn", team);
+    self.turn_started = Instant::now();
+
+    for (_, entities) in self.teams.iter_mut() {
+      for entity in entities.iter_mut() {
+        entity.damaged_this_turn = false;
+      }
+    }
+    for entity in self.teams[self.turn].1.iter_mut() {
+      entity.tick_cooldowns();
+    }
+
+    // Stacked damage goes off at the start of each of the team's turns
+    let auras: Vec<Vec<&'static Aura>> = (0..self.teams[self.turn].1.len())
+      .map(|index| self.auras_on((index << 2 | self.turn) as u8)).collect();
+    for (entity, auras) in self.teams[self.turn].1.iter_mut().zip(auras) {
+      result += &entity.tick_stacks(&auras, self.locale);
+      result += &entity.burn(&auras, self.ruleset.damage, self.locale);
+    }
+    result += &self.resolve_deaths();
+    result += &self.check_phase_triggers(AbilityTriggerType::TurnStart, self.turn);
+
+    result
+  }
+
+  pub fn end_turn(&mut self) -> String {
+    let team = match self.current_team() {
+      Some(team) => team,
+      None => return self.locale.get("scene.no_teams").to_string()
+    };
+    if self.finished {
+      return self.locale.get("scene.battle_over").to_string();
+    }
+
+    if let Some(deck) = self.decks.get_mut(team) {
+      deck.end_turn();
+    }
+
+    let mut result = self.check_phase_triggers(AbilityTriggerType::TurnEnd, self.turn);
+    for entity in self.teams[self.turn].1.iter_mut() {
+      result += &entity.tick_statuses(self.locale);
+    }
+
+    self.turn = self.next_standing_team(self.turn + 1);
+    result += &self.start_turn();
+    result
+  }
+
+  /// Time the current team has left to act, if the ruleset limits it
+  fn turn_time_left(&self) -> Option<std::time::Duration> {
+    self.ruleset.turn_time_limit.map(|limit| limit.saturating_sub(self.turn_started.elapsed()))
+  }
+
+  /// End the current team's turn if it ran out of time, telling whoever tried to act
+  fn end_turn_if_expired(&mut self) -> Option<String> {
+    let team = self.current_team()?;
+    if self.turn_time_left()?.is_zero() && self.decks.contains_key(team) {
+      println!("Team \"{}\" ran out of time", team);
+      return Some(tr!(self.locale, "ruleset.time_up", team) + &self.end_turn());
+    }
+    None
+  }
+
+  pub fn describe_hand(&self) -> String {
+    let team = match self.current_team() {
+      Some(team) => team,
+      None => return self.locale.get("scene.no_teams").to_string()
+    };
+
+    let mut result = match self.decks.get(team) {
+      Some(deck) => tr!(self.locale, "hand.title", team, deck.describe_hand(self.locale)),
+      None => return self.locale.get("hand.not_dealt").to_string()
+    };
+
+    if let Some(left) = self.turn_time_left() {
+      result += &tr!(self.locale, "hand.time_left", left.as_secs());
+    }
+
+    if let Some(inventory) = self.inventories.get(team) {
+      result += self.locale.get("hand.inventory");
+      for item in inventory.iter() {
+        result += &Localized(*item, self.locale).to_string();
+      }
+    }
+
+    result
+  }
+
+  /// Use a consumable from the current team's inventory on `target`, paying its cost in energy
+  pub fn use_item(&mut self, item_name: &str, target: u8) -> String {
+    let team = match self.current_team() {
+      Some(team) => team,
+      None => return self.locale.get("scene.no_teams").to_string()
+    };
+    if self.finished {
+      return self.locale.get("scene.battle_over").to_string();
+    }
+
+    let index = match self.inventories.get(team)
+      .and_then(|inventory| inventory.iter().position(|item| item.name.eq_ignore_ascii_case(item_name))) {
+      Some(index) => index,
+      None => return tr!(self.locale, "item.missing", team, item_name)
+    };
+    if let Some(result) = self.end_turn_if_expired() {
+      return result;
+    }
+    let item = self.inventories[team][index];
+
+    match self.get_entity_from_id(target) {
+      Some(e) if !e.is_alive() && item.revive == 0 => return tr!(self.locale, "item.fainted", item.name, e.name, target),
+      Some(e) if e.is_alive() && item.only_revives() =>
+        return tr!(self.locale, "item.nothing_to_revive", item.name, e.name, target),
+      // Items only ever help, they are kept for the team's own creatures
+      Some(e) if target & 3 != self.turn as u8 => return tr!(self.locale, "item.wrong_team", item.name, e.name, target, team),
+      None => return tr!(self.locale, "scene.no_entity", target),
+      _ => {}
+    }
+
+    if let Some(deck) = self.decks.get_mut(team) {
+      if !deck.spend(item.cost) {
+        return tr!(self.locale, "item.no_energy", item.name, item.cost);
+      }
+    }
+    self.inventories.get_mut(team).unwrap().remove(index);
+
+    let locale = self.locale;
+    let e = self.get_mut_entity_from_id(target).unwrap();
+    println!("Team \"{}\" used \"{}\" on \"{}\"#{}", team, item.name, e.name, target);
+    let mut result = tr!(locale, "item.used", team, item.name, e.name, target);
+    result += &item.apply(e, locale);
+
+    result
+  }
+
+  /// Play a card from the current team's hand.
+  /// Attack cards are used by `user` on `target`, ability cards are taught to `user`.
+  pub fn play_card(&mut self, card_index: usize, user: u8, target: Option<u8>) -> String {
+    let team = match self.current_team() {
+      Some(team) => team,
+      None => return self.locale.get("scene.no_teams").to_string()
+    };
+    if self.finished {
+      return self.locale.get("scene.battle_over").to_string();
+    }
+
+    let card = match self.decks.get(team).and_then(|deck| deck.hand.get(card_index)) {
+      Some(card) => *card,
+      None => return tr!(self.locale, "card.missing", card_index, team)
+    };
+    if let Some(result) = self.end_turn_if_expired() {
+      return result;
+    }
+
+    let e = match self.get_entity_from_id(user) {
+      Some(e) => e,
+      None => return tr!(self.locale, "scene.no_entity", user)
+    };
+
+    if user & 3 != self.turn as u8 {
+      return tr!(self.locale, "card.wrong_team", e.name, user, team);
+    }
+    if !e.is_alive() {
+      return tr!(self.locale, "card.fainted", e.name, user, card.name());
+    }
+    if let Some(status) = e.incapacitated_by() {
+      return tr!(self.locale, "status.cannot_act", e.name, user, Localized(&status, self.locale));
+    }
+    if !self.decks[team].can_play(card_index) {
+      return tr!(self.locale, "card.no_energy", card.name(), card.cost());
+    }
+
+    match card {
+      Card::Attack(attack) => {
+        let target = match target {
+          Some(target) => target,
+          None => return tr!(self.locale, "card.needs_target", attack.name)
+        };
+        match self.get_entity_from_id(target) {
+          Some(t) if !t.is_alive() =>
+            return tr!(self.locale, "card.target_fainted", t.name, target),
+          None => return tr!(self.locale, "scene.no_target", target),
+          _ => {}
+        }
+        if e.get_attack(attack.name).is_none() {
+          return tr!(self.locale, "scene.unknown_attack", e.name, user, attack.name);
+        }
+        if let Err(result) = self.check_target(user, target) {
+          return result;
+        }
+
+        self.decks.get_mut(team).unwrap().play(card_index);
+        self.use_attack(*attack, user, target)
+      },
+      Card::Ability(ability) => {
+        if e.known_abilities().any(|a| a.name == ability.name) {
+          return tr!(self.locale, "card.known_ability", e.name, user, ability.name);
+        }
+        if !e.attributes.contains_all(ability.required_traits) || e.attributes.contains_any(ability.forbidden_traits) {
+          return tr!(self.locale, "card.cannot_learn", e.name, user, ability.name);
+        }
+
+        self.decks.get_mut(team).unwrap().play(card_index);
+        let locale = self.locale;
+        let e = self.get_mut_entity_from_id(user).unwrap();
+        e.battle_abilities.push(ability);
+        println!("\"{}\"#{} learned the \"{}\" ability", e.name, user, ability.name);
+        tr!(locale, "card.learned_ability", e.name, user, ability.name)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn slime() -> Entity {
+    templates::slime().build_with(&mut StdRng::seed_from_u64(0))
+  }
+
+  /// A seeded scene with one slime on each of teams "A" and "B", already dealt
+  fn battle() -> Scene {
+    let mut scene = Scene::new();
+    scene.seed(0);
+    scene.register("A", &slime());
+    scene.register("B", &slime());
+    scene.deal(None);
+    scene
+  }
+
+  fn knock_out(scene: &mut Scene, team: usize) {
+    for entity in scene.teams[team].1.iter_mut() {
+      entity.current_health = 0;
+    }
+  }
+
+  #[test]
+  fn losers_earn_battle_experience() {
+    let mut scene = battle();
+    knock_out(&mut scene, 1);
+    scene.resolve_deaths();
+
+    assert!(scene.finished);
+    let winner = &scene.teams[0].1[0];
+    let loser = &scene.teams[1].1[0];
+    assert_eq!((winner.level, winner.experience), (2, BATTLE_EXPERIENCE + VICTORY_EXPERIENCE - 20));
+    assert_eq!((loser.level, loser.experience), (1, BATTLE_EXPERIENCE));
+  }
+
+  #[test]
+  fn draws_give_no_victory_experience() {
+    let mut scene = battle();
+    knock_out(&mut scene, 0);
+    knock_out(&mut scene, 1);
+    scene.resolve_deaths();
+
+    assert!(scene.finished);
+    for (_, entities) in scene.teams.iter() {
+      assert_eq!((entities[0].level, entities[0].experience), (1, BATTLE_EXPERIENCE));
+    }
+  }
+
+  #[test]
+  fn dealing_again_starts_a_new_battle() {
+    let mut scene = battle();
+    knock_out(&mut scene, 1);
+    scene.resolve_deaths();
+    assert!(scene.finished);
+    assert_eq!(scene.take_finished_entities().len(), 2);
+
+    for entity in scene.teams[1].1.iter_mut() {
+      entity.revive(100);
+    }
+    scene.deal(None);
+    assert!(!scene.finished);
+    assert!(scene.finished_entities.is_empty());
+
+    knock_out(&mut scene, 0);
+    scene.resolve_deaths();
+    assert!(scene.finished);
+    let loser = &scene.teams[0].1[0];
+    assert_eq!((loser.level, loser.experience), (2, BATTLE_EXPERIENCE));
+    assert_eq!(scene.take_finished_entities().len(), 2);
+  }
+
+  #[test]
+  fn same_seed_plays_the_same_battle() {
+    let mut duelist = slime();
+    duelist.attacks = vec![&templates::HEAD_BUMP, &templates::ENGULF];
+    duelist.abilities = vec![&templates::POISON_TOUCH];
+    let play = || {
+      let mut scene = Scene::new();
+      scene.seed(42);
+      scene.register("A", &duelist);
+      scene.register("B", &duelist);
+      let mut log = scene.deal(None);
+      for turn in 0..6 {
+        let (user, target) = if turn % 2 == 0 { (0, 1) } else { (1, 0) };
+        log += &scene.describe_hand();
+        log += &scene.play_card(0, user, Some(target));
+        log += &scene.end_turn();
+      }
+      log
+    };
+
+    assert_eq!(play(), play());
+  }
+
+  /// Health left on the confused attacker's side after it attacked the other team 20 times under `ruleset`
+  fn confused_attacks(ruleset: &'static Ruleset) -> (u8, u8) {
+    let mut scene = Scene::new();
+    scene.seed(0);
+    scene.ruleset = ruleset;
+    let mut sturdy = slime();
+    sturdy.max_health = 200;
+    sturdy.current_health = 200;
+    let attacker = scene.register("A", &sturdy);
+    let target = scene.register("B", &sturdy);
+    let ally = scene.register("A", &sturdy);
+    scene.get_mut_entity_from_id(attacker).unwrap().add_status(Status::Confuse, 99, Locale::English);
+
+    for _ in 0..20 {
+      scene.attack(templates::HEAD_BUMP.name, attacker, target);
+    }
+    (scene.get_entity_from_id(attacker).unwrap().current_health, scene.get_entity_from_id(ally).unwrap().current_health)
+  }
+
+  #[test]
+  fn confused_creatures_turn_on_their_team() {
+    assert_ne!(confused_attacks(ruleset::standard()), (200, 200));
+  }
+
+  #[test]
+  fn confusion_respects_friendly_fire() {
+    assert_eq!(confused_attacks(ruleset::get("Tournament").unwrap()), (200, 200));
+  }
+
+  #[test]
+  fn revive_items_are_kept_for_fainted_creatures() {
+    let mut scene = battle();
+    let feathers = |scene: &Scene| scene.inventories["A"].iter().filter(|item| item.name == "Phoenix Feather").count();
+    let before = feathers(&scene);
+
+    assert_eq!(scene.use_item("Phoenix Feather", 0), tr!(Locale::English, "item.nothing_to_revive", "Phoenix Feather", "Slime", 0));
+    assert_eq!(feathers(&scene), before);
+    assert_eq!(scene.decks["A"].energy, deck::MAX_ENERGY);
+  }
+
+  #[test]
+  fn nothing_can_be_played_once_the_battle_is_over() {
+    let mut scene = battle();
+    knock_out(&mut scene, 1);
+    scene.resolve_deaths();
+
+    let over = Locale::English.get("scene.battle_over");
+    assert_eq!(scene.play_card(0, 0, Some(1)), over);
+    assert_eq!(scene.use_item("Potion", 0), over);
+    assert_eq!(scene.end_turn(), over);
+    assert_eq!(scene.turn, 0);
+  }
+
+  #[test]
+  fn knocked_out_teams_lose_their_turns() {
+    let mut scene = Scene::new();
+    scene.seed(0);
+    for team in ["A", "B", "C"] {
+      scene.register(team, &slime());
+    }
+    scene.deal(None);
+    knock_out(&mut scene, 1);
+    scene.resolve_deaths();
+    assert!(!scene.finished);
+
+    scene.end_turn();
+    assert_eq!(scene.turn, 2);
+    scene.end_turn();
+    assert_eq!(scene.turn, 0);
   }
 }
@@ -0,0 +1,231 @@
+use std::fmt::Display;
+use rand::{Rng, seq::SliceRandom};
+
+use crate::entity::{Entity, Attack, Ability, ability::describe_effects};
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+pub const HAND_SIZE: usize = 5;
+pub const MAX_ENERGY: u8 = 3;
+pub const ATTACK_CARD_COPIES: usize = 3;
+pub const ABILITY_CARD_COST: u8 = 2;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Card {
+  Attack(&'static Attack),
+  Ability(&'static Ability)
+}
+
+impl Card {
+  pub fn name(&self) -> &'static str {
+    match self {
+      Card::Attack(attack) => attack.name,
+      Card::Ability(ability) => ability.name
+    }
+  }
+
+  pub fn cost(&self) -> u8 {
+    match self {
+      Card::Attack(attack) => attack.cost,
+      Card::Ability(_) => ABILITY_CARD_COST
+    }
+  }
+}
+
+impl Display for Card {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Card> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self.0 {
+      Card::Attack(attack) => write!(f, "{}", Localized(*attack, self.1)),
+      Card::Ability(ability) => writeln!(f, "  \\+ `{}` {} 🔵\n    {}, {}.", ability.name,
+        ABILITY_CARD_COST, Localized(&ability.trigger, self.1), describe_effects(ability.effects, self.1))
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Deck {
+  pub draw_pile: Vec<Card>,
+  pub hand: Vec<Card>,
+  pub discard_pile: Vec<Card>,
+  pub energy: u8
+}
+
+impl Deck {
+  pub fn new(mut cards: Vec<Card>, rng: &mut impl Rng) -> Self {
+    cards.shuffle(rng);
+    Deck { draw_pile: cards, hand: Vec::new(), discard_pile: Vec::new(), energy: 0 }
+  }
+
+  /// Build a deck out of every attack and ability known by a team
+  pub fn from_entities(entities: &[Entity], rng: &mut impl Rng) -> Self {
+    let mut cards = Vec::new();
+
+    for entity in entities.iter() {
+      for attack in entity.known_attacks() {
+        for _ in 0..ATTACK_CARD_COPIES {
+          cards.push(Card::Attack(attack));
+        }
+      }
+      for ability in entity.known_abilities() {
+        cards.push(Card::Ability(ability));
+      }
+    }
+
+    Deck::new(cards, rng)
+  }
+
+  /// Draw a single card, shuffling the discard pile back in when the draw pile runs out
+  pub fn draw(&mut self, rng: &mut impl Rng) -> Option<Card> {
+    if self.draw_pile.is_empty() {
+      self.draw_pile.append(&mut self.discard_pile);
+      self.draw_pile.shuffle(rng);
+    }
+
+    let card = self.draw_pile.pop()?;
+    self.hand.push(card);
+    Some(card)
+  }
+
+  pub fn start_turn(&mut self, rng: &mut impl Rng) {
+    self.energy = MAX_ENERGY;
+    while self.hand.len() < HAND_SIZE {
+      if self.draw(rng).is_none() {
+        break;
+      }
+    }
+  }
+
+  pub fn end_turn(&mut self) {
+    self.discard_pile.append(&mut self.hand);
+  }
+
+  pub fn can_play(&self, index: usize) -> bool {
+    match self.hand.get(index) {
+      Some(card) => card.cost() <= self.energy,
+      None => false
+    }
+  }
+
+  /// Pay for something other than a card, returns whether there was enough energy
+  pub fn spend(&mut self, cost: u8) -> bool {
+    if cost > self.energy {
+      return false;
+    }
+
+    self.energy -= cost;
+    true
+  }
+
+  /// Remove a card from the hand and pay its cost, the card goes to the discard pile
+  pub fn play(&mut self, index: usize) -> Option<Card> {
+    if !self.can_play(index) {
+      return None;
+    }
+
+    let card = self.hand.remove(index);
+    self.energy -= card.cost();
+    self.discard_pile.push(card);
+    Some(card)
+  }
+
+  pub fn describe_hand(&self, locale: Locale) -> String {
+    let mut result = tr!(locale, "hand.energy", self.energy, MAX_ENERGY,
+      self.draw_pile.len(), self.discard_pile.len());
+
+    for (index, card) in self.hand.iter().enumerate() {
+      result += &format!("[{}]{}", index, Localized(card, locale));
+    }
+
+    result
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use rand::{SeedableRng, rngs::StdRng};
+  use crate::entity::templates::{HEAD_BUMP, ENGULF, POISON_TOUCH};
+
+  fn names(cards: &[Card]) -> Vec<&'static str> {
+    let mut names: Vec<&str> = cards.iter().map(|card| card.name()).collect();
+    names.sort();
+    names
+  }
+
+  fn rng() -> StdRng {
+    StdRng::seed_from_u64(0)
+  }
+
+  fn sample_deck() -> Deck {
+    let mut cards = Vec::new();
+    for _ in 0..4 {
+      cards.extend([Card::Attack(&HEAD_BUMP), Card::Attack(&ENGULF)]);
+    }
+    cards.push(Card::Ability(&POISON_TOUCH));
+    Deck::new(cards, &mut rng())
+  }
+
+  #[test]
+  fn shuffling_keeps_every_card() {
+    let deck = sample_deck();
+    assert_eq!(deck.draw_pile.len(), 9);
+    assert_eq!(names(&deck.draw_pile), names(&sample_deck().draw_pile));
+    // Seeded shuffles always come out the same way
+    let order: Vec<&str> = deck.draw_pile.iter().map(|card| card.name()).collect();
+    assert_eq!(order, ["Engulf", "Head Bump", "Poison Touch", "Head Bump", "Engulf", "Head Bump", "Engulf", "Head Bump",
+      "Engulf"]);
+    assert!(deck.hand.is_empty() && deck.discard_pile.is_empty());
+    assert_eq!(deck.energy, 0);
+  }
+
+  #[test]
+  fn start_turn_fills_the_hand() {
+    let mut deck = sample_deck();
+    deck.start_turn(&mut rng());
+    assert_eq!(deck.hand.len(), HAND_SIZE);
+    assert_eq!(deck.draw_pile.len(), 9 - HAND_SIZE);
+    assert_eq!(deck.energy, MAX_ENERGY);
+  }
+
+  #[test]
+  fn draw_shuffles_the_discard_pile_back_in() {
+    let mut deck = sample_deck();
+    deck.discard_pile.append(&mut deck.draw_pile);
+
+    assert!(deck.draw(&mut rng()).is_some());
+    assert_eq!(deck.hand.len(), 1);
+    assert_eq!(deck.draw_pile.len(), 8);
+    assert!(deck.discard_pile.is_empty());
+  }
+
+  #[test]
+  fn draw_stops_when_every_card_is_in_hand() {
+    let mut deck = Deck::new(vec![Card::Attack(&HEAD_BUMP)], &mut rng());
+    assert!(deck.draw(&mut rng()).is_some());
+    assert!(deck.draw(&mut rng()).is_none());
+    deck.start_turn(&mut rng());
+    assert_eq!(deck.hand.len(), 1);
+  }
+
+  #[test]
+  fn playing_pays_and_discards() {
+    let mut deck = Deck::new(vec![Card::Attack(&ENGULF), Card::Attack(&ENGULF)], &mut rng());
+    deck.start_turn(&mut rng());
+
+    assert!(deck.play(0).is_some());
+    assert_eq!(deck.energy, MAX_ENERGY - ENGULF.cost);
+    assert_eq!(deck.discard_pile.len(), 1);
+    assert!(!deck.can_play(0));
+    assert!(deck.play(0).is_none());
+
+    deck.end_turn();
+    assert!(deck.hand.is_empty());
+    assert_eq!(deck.discard_pile.len(), 2);
+  }
+}
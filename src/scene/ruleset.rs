@@ -0,0 +1,91 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::entity::stats::{DamageRules, Rounding};
+use crate::locale::{Locale, Localized};
+use crate::tr;
+
+/// Rules a battle is played by, picked from `PRESETS` when cards are dealt
+#[derive(Debug)]
+pub struct Ruleset {
+  pub name: &'static str,
+  pub description: &'static str,
+  /// Whether attacks can be aimed at allies, confused creatures included
+  pub friendly_fire: bool,
+  /// Whether creatures can aim attacks at themselves
+  pub self_targeting: bool,
+  /// Most entities a team can have registered, fainted ones included, before summons start failing
+  pub max_team_size: usize,
+  /// Most teams taking part in the battle, at most `TEAM_NAMES.len()`
+  pub max_teams: usize,
+  pub damage: DamageRules,
+  /// Damage multiplier of critical hits
+  pub crit_multiplier: f32,
+  /// Percentage of the damage dealt by vampiric attacks healed back by the attacker
+  pub vampiric_heal: u8,
+  /// Time a team has to act before its turn ends on its own, checked whenever it plays
+  pub turn_time_limit: Option<Duration>,
+  /// Rounds of abilities going off as entities faint resolved at once, in case they keep knocking each other out
+  pub chain_depth: usize
+}
+
+pub static PRESETS: [Ruleset; 4] = [
+  Ruleset { name: "Standard", description: "Anything goes, the way battles have always been fought.",
+    friendly_fire: true, self_targeting: true, max_team_size: 8, max_teams: 4, damage: DamageRules::STANDARD,
+    crit_multiplier: 1.5, vampiric_heal: 100, turn_time_limit: None, chain_depth: 8 },
+  Ruleset { name: "Tournament", description: "One on one, small teams, no hitting your own side and a minute per turn.",
+    friendly_fire: false, self_targeting: false, max_team_size: 4, max_teams: 2,
+    damage: DamageRules { rounding: Rounding::Down, weakness: 1.5, resistance: 0.5, immunity: 0.0 },
+    crit_multiplier: 1.5, vampiric_heal: 50, turn_time_limit: Some(Duration::from_secs(60)), chain_depth: 4 },
+  Ruleset { name: "Blitz", description: "Three creatures a team and thirty seconds to make up your mind.",
+    friendly_fire: true, self_targeting: true, max_team_size: 3, max_teams: 4, damage: DamageRules::STANDARD,
+    crit_multiplier: 1.5, vampiric_heal: 100, turn_time_limit: Some(Duration::from_secs(30)), chain_depth: 8 },
+  Ruleset { name: "Chaos", description: "Weaknesses hurt more, resistances help less and critical hits are brutal.",
+    friendly_fire: true, self_targeting: true, max_team_size: 8, max_teams: 4,
+    damage: DamageRules { rounding: Rounding::Up, weakness: 2.0, resistance: 0.75, immunity: 0.25 },
+    crit_multiplier: 2.0, vampiric_heal: 100, turn_time_limit: None, chain_depth: 16 }
+];
+
+/// Rules of battles nobody picked a preset for
+pub fn standard() -> &'static Ruleset {
+  &PRESETS[0]
+}
+
+pub fn get(name: &str) -> Option<&'static Ruleset> {
+  PRESETS.iter().find(|ruleset| ruleset.name.eq_ignore_ascii_case(name))
+}
+
+impl Ruleset {
+  /// Health healed back by a vampiric attack dealing `damage`
+  pub fn vampiric_healing(&self, damage: u8) -> u8 {
+    ((damage as u16 * self.vampiric_heal as u16) / 100).min(u8::MAX as u16) as u8
+  }
+}
+
+impl Display for Ruleset {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Localized(self, Locale::English).fmt(f)
+  }
+}
+
+impl Display for Localized<'_, Ruleset> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let Localized(ruleset, locale) = *self;
+    let mut rules = vec![
+      tr!(locale, "ruleset.teams", ruleset.max_teams, ruleset.max_team_size),
+      locale.get(if ruleset.friendly_fire { "ruleset.friendly_fire" } else { "ruleset.no_friendly_fire" }).to_string()
+    ];
+    if !ruleset.self_targeting {
+      rules.push(locale.get("ruleset.no_self_targeting").to_string());
+    }
+    rules.push(tr!(locale, "ruleset.resistances", ruleset.damage.weakness, ruleset.damage.resistance,
+      ruleset.damage.immunity));
+    rules.push(tr!(locale, "ruleset.critical", ruleset.crit_multiplier));
+    rules.push(tr!(locale, "ruleset.vampiric", ruleset.vampiric_heal));
+    if let Some(limit) = ruleset.turn_time_limit {
+      rules.push(tr!(locale, "ruleset.time_limit", limit.as_secs()));
+    }
+
+    writeln!(f, "**{}**  {}\n  {}", ruleset.name, ruleset.description, rules.join(", "))
+  }
+}